@@ -550,7 +550,7 @@ impl Connector {
     }
 
     pub fn is_pre_processing_required_before_authorize(self) -> bool {
-        matches!(self, Self::Airwallex)
+        matches!(self, Self::Airwallex | Self::Wave)
     }
 
     pub fn get_payment_methods_supporting_extended_authorization(self) -> HashSet<PaymentMethod> {
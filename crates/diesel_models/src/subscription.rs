@@ -1,5 +1,6 @@
 use common_utils::pii::SecretSerdeValue;
 use diesel::{AsChangeset, Identifiable, Insertable, Queryable, Selectable};
+use masking::{PeekInterface, Secret, StrongSecret};
 use serde::{Deserialize, Serialize};
 
 use crate::schema::subscription;
@@ -12,7 +13,7 @@ pub struct SubscriptionNew {
     billing_processor: Option<String>,
     payment_method_id: Option<String>,
     mca_id: Option<String>,
-    client_secret: Option<String>,
+    client_secret: Option<Secret<String>>,
     connector_subscription_id: Option<String>,
     merchant_id: common_utils::id_type::MerchantId,
     customer_id: common_utils::id_type::CustomerId,
@@ -33,7 +34,7 @@ pub struct Subscription {
     pub billing_processor: Option<String>,
     pub payment_method_id: Option<String>,
     pub mca_id: Option<String>,
-    pub client_secret: Option<String>,
+    pub client_secret: Option<Secret<String>>,
     pub connector_subscription_id: Option<String>,
     pub merchant_id: common_utils::id_type::MerchantId,
     pub customer_id: common_utils::id_type::CustomerId,
@@ -58,7 +59,7 @@ impl SubscriptionNew {
         billing_processor: Option<String>,
         payment_method_id: Option<String>,
         mca_id: Option<String>,
-        client_secret: Option<String>,
+        client_secret: Option<Secret<String>>,
         connector_subscription_id: Option<String>,
         merchant_id: common_utils::id_type::MerchantId,
         customer_id: common_utils::id_type::CustomerId,
@@ -82,6 +83,16 @@ impl SubscriptionNew {
     }
 }
 
+impl Subscription {
+    /// Compare a client secret from a request with the stored one in constant time
+    pub fn client_secret_matches(&self, req_client_secret: &str) -> bool {
+        self.client_secret.as_ref().is_some_and(|client_secret| {
+            StrongSecret::<String>::new(client_secret.peek().clone())
+                == StrongSecret::new(req_client_secret.to_string())
+        })
+    }
+}
+
 impl SubscriptionUpdate {
     pub fn new(payment_method_id: Option<String>, status: Option<String>) -> Self {
         Self {
@@ -91,3 +102,43 @@ impl SubscriptionUpdate {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    #![allow(clippy::unwrap_used)]
+    use super::*;
+
+    fn subscription_with_secret(client_secret: Option<&str>) -> Subscription {
+        let now = common_utils::date_time::now();
+        Subscription {
+            id: 1,
+            subscription_id: "sub_test".to_string(),
+            status: "active".to_string(),
+            billing_processor: None,
+            payment_method_id: None,
+            mca_id: None,
+            client_secret: client_secret.map(|secret| Secret::new(secret.to_string())),
+            connector_subscription_id: None,
+            merchant_id: common_utils::id_type::MerchantId::default(),
+            customer_id: common_utils::id_type::CustomerId::default(),
+            metadata: None,
+            created_at: now,
+            modified_at: now,
+        }
+    }
+
+    #[test]
+    fn test_client_secret_is_redacted_in_debug_output() {
+        let subscription = subscription_with_secret(Some("sub_test_secret_abc123"));
+        assert!(!format!("{subscription:?}").contains("sub_test_secret_abc123"));
+    }
+
+    #[test]
+    fn test_client_secret_matches() {
+        let subscription = subscription_with_secret(Some("sub_test_secret_abc123"));
+        assert!(subscription.client_secret_matches("sub_test_secret_abc123"));
+        assert!(!subscription.client_secret_matches("sub_test_secret_abc124"));
+        assert!(!subscription.client_secret_matches("sub_test_secret"));
+        assert!(!subscription_with_secret(None).client_secret_matches("sub_test_secret_abc123"));
+    }
+}
@@ -1,21 +1,39 @@
 pub mod transformers;
 
+use std::sync::{
+    atomic::{AtomicUsize, Ordering},
+    LazyLock, OnceLock,
+};
+
+use common_enums::enums;
 use common_utils::{
+    crypto::{self, VerifySignature},
+    date_time,
     errors::CustomResult,
-    ext_traits::BytesExt,
+    ext_traits::{ByteSliceExt, BytesExt, ValueExt},
+    id_type,
+    pii::SecretSerdeValue,
     request::{Method, Request, RequestBuilder, RequestContent},
+    types::MinorUnit,
 };
 use error_stack::ResultExt;
+use futures::StreamExt;
 use hyperswitch_domain_models::{
-    router_data::ErrorResponse,
+    router_data::{ConnectorAuthType, ErrorResponse, RouterData},
     router_flow_types::{
-        payments::{Authorize, Capture, PSync, Void, PaymentMethodToken, Session, SetupMandate},
+        payments::{
+            Authorize, Capture, PSync, Void, PaymentMethodToken, PreProcessing, Session,
+            SetupMandate,
+        },
         refunds::{Execute, RSync},
         access_token_auth::AccessTokenAuth,
     },
-    router_request_types::{PaymentsAuthorizeData, PaymentsCancelData, PaymentsCaptureData, PaymentsSyncData, RefundsData, PaymentsSessionData, SetupMandateRequestData, PaymentMethodTokenizationData, AccessTokenRequestData},
-    router_response_types::{PaymentsResponseData, RefundsResponseData},
-    types::{PaymentsAuthorizeRouterData, PaymentsCancelRouterData, PaymentsCaptureRouterData, PaymentsSyncRouterData, RefundSyncRouterData, RefundsRouterData},
+    router_request_types::{PaymentsAuthorizeData, PaymentsCancelData, PaymentsCaptureData, PaymentsPreProcessingData, PaymentsSyncData, RefundsData, PaymentsSessionData, SetupMandateRequestData, PaymentMethodTokenizationData, AccessTokenRequestData, ResponseId},
+    router_response_types::{
+        ConnectorInfo, PaymentMethodDetails, PaymentsResponseData, RefundsResponseData,
+        SupportedPaymentMethods, SupportedPaymentMethodsExt,
+    },
+    types::{PaymentsAuthorizeRouterData, PaymentsCancelRouterData, PaymentsCaptureRouterData, PaymentsPreProcessingRouterData, PaymentsSessionRouterData, PaymentsSyncRouterData, RefundSyncRouterData, RefundsRouterData, SetupMandateRouterData, TokenizationRouterData},
 };
 use hyperswitch_interfaces::{
     api::{
@@ -26,11 +44,12 @@ use hyperswitch_interfaces::{
     consts::{NO_ERROR_CODE, NO_ERROR_MESSAGE},
     errors,
     events::connector_api_logs::ConnectorEvent,
-    types::{PaymentsAuthorizeType, RefundExecuteType, Response},
+    types::Response,
     webhooks::{IncomingWebhook, IncomingWebhookRequestDetails},
 };
 use api_models::webhooks::{IncomingWebhookEvent, ObjectReferenceId};
 use masking::{Mask, Maskable, PeekInterface, Secret};
+use router_env::{instrument, tracing};
 
 use crate::{
     constants::headers,
@@ -41,10 +60,46 @@ use crate::{
 use self::transformers as wave;
 use self::transformers::WaveCheckoutSessionResponse;
 
+/// Count a Wave flow outcome, tagged by flow and `success` / `failure`, for alerting on error-rate spikes
+fn record_wave_flow_outcome(flow: &'static str, status: &'static str) {
+    crate::metrics::WAVE_FLOW_OUTCOME.add(
+        1,
+        router_env::metric_attributes!(("connector", "wave"), ("flow", flow), ("status", status)),
+    );
+}
+
+/// Outcome of a handled response, which still fails the flow when Wave reported an error inside it
+fn get_wave_flow_response_status<F, Req, Resp>(
+    router_data: &CustomResult<RouterData<F, Req, Resp>, errors::ConnectorError>,
+) -> &'static str {
+    match router_data {
+        Ok(router_data) if router_data.response.is_ok() => "success",
+        _ => "failure",
+    }
+}
+
+fn record_wave_flow_response<F, Req, Resp>(
+    flow: &'static str,
+    router_data: &CustomResult<RouterData<F, Req, Resp>, errors::ConnectorError>,
+) {
+    record_wave_flow_outcome(flow, get_wave_flow_response_status(router_data));
+}
+
+/// Drop the request body from the connector event for accounts that set `include_request_body_in_events`
+/// to false. When kept, it is the router's masked serialization, so customer PII stays masked.
+fn apply_wave_event_verbosity(event: &mut ConnectorEvent, connector_meta_data: Option<&SecretSerdeValue>) {
+    let include_request_body = wave::parse_wave_connector_metadata(connector_meta_data)
+        .and_then(|meta| meta.include_request_body_in_events)
+        .unwrap_or(true);
+    if !include_request_body {
+        event.set_request_body(&serde_json::json!({ "request_type": "OMITTED" }));
+    }
+}
+
 // Endpoints
-const WAVE_BASE_URL: &str = "https://api.wave.com/";
 const WAVE_CHECKOUT_SESSIONS: &str = "checkout/sessions";
 const WAVE_CHECKOUT_SESSION_STATUS: &str = "checkout/sessions/{session_id}";
+const WAVE_CHECKOUT_SESSION_SEARCH: &str = "checkout/sessions/search?reference={reference}";
 const WAVE_CANCEL_PAYMENT: &str = "v1/transactions/{txn_id}/cancel";
 const WAVE_REFUND_FOR_TXN: &str = "v1/transactions/{txn_id}/refunds";
 const WAVE_REFUND_STATUS: &str = "v1/refunds/{refund_id}";
@@ -53,9 +108,44 @@ const WAVE_REFUND_STATUS: &str = "v1/refunds/{refund_id}";
 //const WAVE_AGGREGATED_MERCHANTS: &str = "v1/aggregated_merchants";
 const WAVE_AGGREGATED_MERCHANT_BY_ID: &str = "v1/aggregated_merchants/{id}";
 const WAVE_AGGREGATED_MERCHANT_LIST: &str = "v1/aggregated_merchants";
+const WAVE_AGGREGATED_MERCHANT_LIST_PAGE: &str = "v1/aggregated_merchants?limit={limit}";
+const WAVE_AGGREGATED_MERCHANT_LIST_PAGE_AFTER: &str =
+    "v1/aggregated_merchants?limit={limit}&cursor={cursor}";
 const WAVE_AGGREGATED_MERCHANT_CREATE: &str = "v1/aggregated_merchants";
 const WAVE_AGGREGATED_MERCHANT_UPDATE: &str = "v1/aggregated_merchants/{id}";
 const WAVE_AGGREGATED_MERCHANT_DELETE: &str = "v1/aggregated_merchants/{id}";
+/// Page size requested when the caller leaves `limit` unset, rather than Wave's server default
+const WAVE_AGGREGATED_MERCHANT_LIST_DEFAULT_LIMIT: u32 = 50;
+/// Largest page Wave serves for the aggregated merchant list
+const WAVE_AGGREGATED_MERCHANT_LIST_MAX_LIMIT: u32 = 100;
+const WAVE_WEBHOOKS: &str = "v1/webhooks";
+const WAVE_WEBHOOK_BY_ID: &str = "v1/webhooks/{webhook_id}";
+
+/// Fill a `WAVE_*` endpoint template, failing if a value is empty or a placeholder is left unsubstituted.
+/// Values are percent-encoded, so an id can only ever fill its own path segment or query value
+fn build_wave_url(
+    template: &str,
+    params: &[(&str, &str)],
+) -> CustomResult<String, errors::ConnectorError> {
+    let mut url = template.to_string();
+    for (key, value) in params {
+        let placeholder = format!("{{{key}}}");
+        if value.is_empty() {
+            return Err(error_stack::report!(errors::ConnectorError::RequestEncodingFailed)
+                .attach_printable(format!("Empty value for {placeholder} in {template}")));
+        }
+        if !url.contains(&placeholder) {
+            return Err(error_stack::report!(errors::ConnectorError::RequestEncodingFailed)
+                .attach_printable(format!("No {placeholder} placeholder in {template}")));
+        }
+        url = url.replace(&placeholder, &urlencoding::encode(value));
+    }
+    if url.contains('{') || url.contains('}') {
+        return Err(error_stack::report!(errors::ConnectorError::RequestEncodingFailed)
+            .attach_printable(format!("Unsubstituted placeholder left in {url}")));
+    }
+    Ok(url)
+}
 
 #[derive(Debug, Clone)]
 pub struct Wave;
@@ -64,8 +154,74 @@ impl Wave {
     pub const fn new() -> &'static Self {
         &Self
     }
+
+    /// Standard header set for Wave API calls: JSON accept, optional JSON content type and masked auth
+    fn build_common_headers(
+        &self,
+        auth_type: &hyperswitch_domain_models::router_data::ConnectorAuthType,
+        include_content_type: bool,
+    ) -> CustomResult<Vec<(String, Maskable<String>)>, errors::ConnectorError> {
+        let mut headers_vec = vec![(
+            headers::ACCEPT.to_string(),
+            "application/json".to_string().into(),
+        )];
+        if include_content_type {
+            headers_vec.push((
+                headers::CONTENT_TYPE.to_string(),
+                self.common_get_content_type().to_string().into(),
+            ));
+        }
+        let mut auth = self.get_auth_header(auth_type)?;
+        headers_vec.append(&mut auth);
+        Ok(headers_vec)
+    }
+
+    /// Error response of a flow, counted as a failure of that flow
+    fn build_flow_error_response(
+        &self,
+        flow: &'static str,
+        res: Response,
+        event_builder: Option<&mut ConnectorEvent>,
+    ) -> CustomResult<ErrorResponse, errors::ConnectorError> {
+        record_wave_flow_outcome(flow, "failure");
+        self.build_error_response(res, event_builder)
+    }
+
+    /// 5xx error response of a flow. Wave being down or failing internally says nothing about the
+    /// payment itself, so the attempt is left open and the code tells the router it may retry
+    fn build_flow_5xx_error_response(
+        &self,
+        flow: &'static str,
+        res: Response,
+        event_builder: Option<&mut ConnectorEvent>,
+    ) -> CustomResult<ErrorResponse, errors::ConnectorError> {
+        let status_code = res.status_code;
+        let error = self.build_flow_error_response(flow, res, event_builder)?;
+        let (code, message) = match status_code {
+            502..=504 => (WAVE_SERVICE_UNAVAILABLE_CODE, WAVE_SERVICE_UNAVAILABLE_MESSAGE),
+            _ => (WAVE_INTERNAL_ERROR_CODE, WAVE_INTERNAL_ERROR_MESSAGE),
+        };
+        Ok(ErrorResponse {
+            code: code.to_string(),
+            message: message.to_string(),
+            // Keep whatever Wave said so the original failure is not lost
+            reason: error.reason.or_else(|| {
+                (error.message != NO_ERROR_MESSAGE).then_some(error.message)
+            }),
+            attempt_status: None,
+            ..error
+        })
+    }
 }
 
+/// Error codes and messages for Wave 5xx answers, both safe to retry since Wave never processed
+/// the request. The router's auto-retry finds them through the `gateway_status_map` rules added by
+/// the `add_wave_retryable_gsm_rules` migration, which match on code and message, so keep them in sync
+pub const WAVE_SERVICE_UNAVAILABLE_CODE: &str = "WAVE_SERVICE_UNAVAILABLE";
+pub const WAVE_SERVICE_UNAVAILABLE_MESSAGE: &str = "Wave is temporarily unavailable";
+pub const WAVE_INTERNAL_ERROR_CODE: &str = "WAVE_INTERNAL_ERROR";
+pub const WAVE_INTERNAL_ERROR_MESSAGE: &str = "Wave failed to process the request";
+
 impl ConnectorCommon for Wave {
     fn id(&self) -> &'static str {
         "wave"
@@ -86,8 +242,16 @@ impl ConnectorCommon for Wave {
         )])
     }
 
-    fn base_url<'a>(&self, _connectors: &'a Connectors) -> &'a str {
-        WAVE_BASE_URL
+    fn base_url<'a>(&self, connectors: &'a Connectors) -> &'a str {
+        connectors.wave.base_url.as_ref()
+    }
+
+    fn apply_event_verbosity(
+        &self,
+        event: &mut ConnectorEvent,
+        connector_meta_data: Option<&SecretSerdeValue>,
+    ) {
+        apply_wave_event_verbosity(event, connector_meta_data);
     }
 
     fn build_error_response(
@@ -95,11 +259,15 @@ impl ConnectorCommon for Wave {
         res: Response,
         _event_builder: Option<&mut ConnectorEvent>,
     ) -> CustomResult<ErrorResponse, errors::ConnectorError> {
-        let response: Result<wave::WaveErrorResponse, _> = res.response.parse_struct("WaveErrorResponse");
+        let response = wave::WaveErrorResponse::parse(&res.response);
         match response {
             Ok(error_res) => Ok(ErrorResponse {
                 code: error_res.code.unwrap_or_else(|| NO_ERROR_CODE.to_string()),
-                message: error_res.message,
+                message: if error_res.message.is_empty() {
+                    NO_ERROR_MESSAGE.to_string()
+                } else {
+                    error_res.message
+                },
                 reason: error_res.details.and_then(|d| d.first().map(|detail| detail.msg.clone())),
                 status_code: res.status_code,
                 attempt_status: None,
@@ -132,11 +300,10 @@ impl Wave {
         if !auth.aggregated_merchants_enabled {
             return Ok(None);
         }
-        
         // Use the aggregated merchant resolver
         WaveAggregatedMerchantResolver::resolve_aggregated_merchant(
             &auth,
-            self.base_url(connectors),
+            connectors.wave.get_merchant_service_base_url(),
             req,
         ).await
     }
@@ -185,7 +352,6 @@ impl Wave {
         if !auth.aggregated_merchants_enabled {
             return Ok(true); // No validation needed if feature is disabled
         }
-        
         if let Some(meta) = metadata {
             // Validate the metadata structure
             wave::validate_wave_connector_metadata(meta)
@@ -197,7 +363,7 @@ impl Wave {
             if let Some(ref merchant_id) = meta.aggregated_merchant_id {
                 let exists = WaveAggregatedMerchantResolver::validate_aggregated_merchant(
                     auth,
-                    self.base_url(connectors),
+                    connectors.wave.get_merchant_service_base_url(),
                     merchant_id,
                 ).await?;
                 
@@ -210,13 +376,41 @@ impl Wave {
         
         Ok(true)
     }
+    
+    /// Verify the configured Wave credentials with a lightweight authenticated call
+    /// This does not create any payment and can be used for readiness probes and onboarding
+    pub async fn validate_credentials(
+        auth: &wave::WaveAuthType,
+        base_url: &str,
+    ) -> CustomResult<(), errors::ConnectorError> {
+        let url = format!("{}{}?limit=1", base_url, WAVE_AGGREGATED_MERCHANT_LIST);
+        let auth_header = format!("Bearer {}", auth.api_key.peek());
+        
+        let response = send_wave_request(
+            wave_http_client()?
+                .get(&url)
+                .header(headers::AUTHORIZATION, auth_header),
+        )
+        .await?;
+            
+        match response.status().as_u16() {
+            200 => Ok(()),
+            401 | 403 => Err(errors::ConnectorError::from(
+                wave::WaveAggregatedMerchantError::AuthenticationFailed,
+            )
+            .into()),
+            _ => wave_api_error(response).await,
+        }
+    }
 }
 
 // Wave Aggregated Merchant Resolution Logic
 pub struct WaveAggregatedMerchantResolver;
 
 impl WaveAggregatedMerchantResolver {
-    /// Resolve aggregated merchant ID for payment, with auto-creation if enabled
+    /// Resolve aggregated merchant ID for payment, with auto-creation if enabled.
+    /// A failed resolution fails the payment or is dropped depending on `on_resolution_failure`
+    #[instrument(skip_all, fields(flow = "resolve_aggregated_merchant"))]
     pub async fn resolve_aggregated_merchant(
         auth: &wave::WaveAuthType,
         base_url: &str,
@@ -229,46 +423,94 @@ impl WaveAggregatedMerchantResolver {
         
         // Try to extract aggregated merchant metadata
         let metadata = wave::extract_wave_connector_metadata(router_data)?;
-        
+        let policy = wave::get_resolution_failure_policy(metadata.as_ref());
+
+        match Self::try_resolve_aggregated_merchant(auth, base_url, router_data, metadata.as_ref()).await {
+            Ok(resolved) => Ok(resolved),
+            Err(e) => match policy {
+                wave::WaveResolutionFailurePolicy::FailPayment => Err(e),
+                wave::WaveResolutionFailurePolicy::Degrade => {
+                    router_env::logger::warn!(
+                        "Aggregated merchant resolution failed, continuing without one: {:?}",
+                        e
+                    );
+                    // Graceful degradation: continue without aggregated merchant
+                    Ok(None)
+                }
+            },
+        }
+    }
+
+    /// Configured aggregated merchant if usable, otherwise an auto-created one when enabled.
+    /// Every failure is returned so that `resolve_aggregated_merchant` applies the merchant's policy
+    async fn try_resolve_aggregated_merchant(
+        auth: &wave::WaveAuthType,
+        base_url: &str,
+        router_data: &PaymentsAuthorizeRouterData,
+        metadata: Option<&wave::WaveConnectorMetadata>,
+    ) -> CustomResult<Option<String>, errors::ConnectorError> {
+        let mut configured_failure = None;
+
         // If metadata exists and has aggregated merchant ID, validate and return it
-        if let Some(meta) = &metadata {
-            if let Some(aggregated_merchant_id) = &meta.aggregated_merchant_id {
-                // Validate the merchant ID exists and is accessible
-                match Self::validate_aggregated_merchant(auth, base_url, aggregated_merchant_id).await {
-                    Ok(true) => return Ok(Some(aggregated_merchant_id.clone())),
-                    Ok(false) => {
-                        router_env::logger::warn!(
-                            "Aggregated merchant ID {} not found or not accessible",
-                            aggregated_merchant_id
-                        );
-                        // Continue to auto-creation if enabled
-                    },
-                    Err(e) => {
-                        router_env::logger::error!(
-                            "Error validating aggregated merchant {}: {:?}",
-                            aggregated_merchant_id,
-                            e
-                        );
-                        // Continue to auto-creation if enabled
-                    }
+        if let Some(aggregated_merchant_id) = metadata.and_then(|meta| meta.aggregated_merchant_id.as_ref()) {
+            // Validate the merchant ID exists and is accessible
+            match Self::validate_aggregated_merchant(auth, base_url, aggregated_merchant_id).await {
+                Ok(true) => return Ok(Some(aggregated_merchant_id.clone())),
+                Ok(false) => {
+                    router_env::logger::warn!(
+                        "Aggregated merchant ID {} not found or not accessible",
+                        aggregated_merchant_id
+                    );
+                    configured_failure = Some(error_stack::report!(
+                        errors::ConnectorError::ProcessingStepFailed(Some(
+                            format!("Aggregated merchant {aggregated_merchant_id} not found or not accessible").into()
+                        ))
+                    ));
+                },
+                Err(e) => {
+                    router_env::logger::error!(
+                        "Error validating aggregated merchant {}: {:?}",
+                        aggregated_merchant_id,
+                        e
+                    );
+                    configured_failure = Some(e);
                 }
             }
         }
         
         // Check if auto-create is enabled
         let auto_create = metadata
-            .as_ref()
             .and_then(|m| m.auto_create_aggregated_merchant)
             .unwrap_or(auth.auto_create_aggregated_merchant);
             
         if auto_create {
             // Attempt to auto-create aggregated merchant
-            Self::auto_create_aggregated_merchant(auth, base_url, router_data, metadata.as_ref()).await
+            Self::auto_create_aggregated_merchant(auth, base_url, router_data, metadata).await
         } else {
-            Ok(None)
+            configured_failure.map_or(Ok(None), Err)
         }
     }
     
+    /// Create a temporary aggregated merchant for one transaction, within the limiter's concurrency cap
+    pub async fn create_temporary_aggregated_merchant(
+        auth: &wave::WaveAuthType,
+        base_url: &str,
+        router_data: &PaymentsAuthorizeRouterData,
+        limiter: &WaveCreationLimiter,
+    ) -> Result<Option<String>, TemporaryCreationError> {
+        let Some(_permit) = limiter.try_acquire() else {
+            router_env::logger::warn!(
+                "Temporary aggregated merchant creation limit of {} reached, skipping",
+                limiter.max_in_flight
+            );
+            return Err(TemporaryCreationError::LimitReached);
+        };
+
+        Self::auto_create_aggregated_merchant(auth, base_url, router_data, None)
+            .await
+            .map_err(|_| TemporaryCreationError::Creation)
+    }
+
     /// Auto-create aggregated merchant based on business profile information with enhanced validation
     async fn auto_create_aggregated_merchant(
         auth: &wave::WaveAuthType,
@@ -286,9 +528,14 @@ impl WaveAggregatedMerchantResolver {
             profile_name
         );
         
+        let tax_registration_id = router_data
+            .l2_l3_data
+            .as_ref()
+            .and_then(|l2_l3_data| l2_l3_data.customer_tax_registration_id.clone());
         let request = match wave::build_aggregated_merchant_request_from_profile(
             &profile_name,
             metadata,
+            tax_registration_id,
         ) {
             Ok(req) => req,
             Err(e) => {
@@ -301,6 +548,13 @@ impl WaveAggregatedMerchantResolver {
             }
         };
         
+        // Manager name and registration identifier are `Secret`s, so this stays redacted
+        router_env::logger::debug!(
+            "Aggregated merchant creation request for profile {}: {:?}",
+            profile_name,
+            request
+        );
+
         match WaveAggregatedMerchantService::create_aggregated_merchant(
             &auth.api_key,
             base_url,
@@ -313,33 +567,43 @@ impl WaveAggregatedMerchantResolver {
                     merchant.id,
                     profile_name
                 );
-                
-                // TODO: Update connector metadata with the new aggregated merchant ID
-                // This would require access to the storage layer to update the merchant connector account
-                
+
                 Ok(Some(merchant.id))
             },
             Err(e) => {
-                // Log the error but don't fail the payment
                 router_env::logger::warn!(
                     "Failed to auto-create aggregated merchant for profile {}: {:?}",
                     profile_name,
                     e
                 );
-                // Graceful degradation: continue without aggregated merchant
-                Ok(None)
+                Err(e)
             }
         }
     }
     
-    /// Validate aggregated merchant exists and is accessible with retry logic
+    /// Validate aggregated merchant exists and is accessible, retrying up to `connectors.wave.max_retries` times
     pub async fn validate_aggregated_merchant(
         auth: &wave::WaveAuthType,
         base_url: &str,
         aggregated_merchant_id: &str,
+    ) -> CustomResult<bool, errors::ConnectorError> {
+        Self::validate_aggregated_merchant_with_retries(
+            auth,
+            base_url,
+            aggregated_merchant_id,
+            wave_service_client()?.max_retries,
+        )
+        .await
+    }
+
+    /// Validate aggregated merchant exists and is active, making at most `max_retries` attempts
+    pub async fn validate_aggregated_merchant_with_retries(
+        auth: &wave::WaveAuthType,
+        base_url: &str,
+        aggregated_merchant_id: &str,
+        max_retries: u32,
     ) -> CustomResult<bool, errors::ConnectorError> {
         // Implement simple retry logic for transient failures
-        let max_retries = 3;
         let mut retry_count = 0;
         
         while retry_count < max_retries {
@@ -348,7 +612,16 @@ impl WaveAggregatedMerchantResolver {
                 base_url,
                 aggregated_merchant_id,
             ).await {
-                Ok(_) => return Ok(true),
+                Ok(merchant) => {
+                    if !merchant.status.is_usable() {
+                        router_env::logger::warn!(
+                            "Aggregated merchant {} exists but is not usable: status={:?}",
+                            aggregated_merchant_id,
+                            merchant.status
+                        );
+                    }
+                    return Ok(merchant.status.is_usable());
+                }
                 Err(e) => {
                     retry_count += 1;
                     if retry_count >= max_retries {
@@ -361,10 +634,14 @@ impl WaveAggregatedMerchantResolver {
                         return Ok(false);
                     }
                     
-                    // Wait before retry (exponential backoff)
-                    // Note: In production, this should use proper async delay
-                    // let delay_ms = 100 * (2_u64.pow(retry_count - 1));
-                    // TODO: Replace with proper async sleep implementation
+                    // Jittered so concurrent payments do not retry against Wave in lockstep
+                    let delay = wave_backoff_with_jitter(
+                        WAVE_RESOLVER_RETRY_BASE_DELAY,
+                        WAVE_RESOLVER_RETRY_MAX_DELAY,
+                        retry_count,
+                        &mut rand::thread_rng(),
+                    );
+                    tokio::time::sleep(delay).await;
                 }
             }
         }
@@ -404,10 +681,18 @@ impl WaveAggregatedMerchantResolver {
                 },
                 AggregatedMerchantFallbackStrategy::CreateTemporary => {
                     // Create a temporary aggregated merchant for this transaction
-                    if let Ok(Some(merchant_id)) = Self::auto_create_aggregated_merchant(
-                        auth, base_url, router_data, None
-                    ).await {
-                        return Ok(Some(merchant_id));
+                    match Self::create_temporary_aggregated_merchant(
+                        auth,
+                        base_url,
+                        router_data,
+                        &wave_service_client()?.temporary_merchant_limiter,
+                    )
+                    .await
+                    {
+                        Ok(Some(merchant_id)) => return Ok(Some(merchant_id)),
+                        // Over the concurrency cap, behave as `Skip`
+                        Err(TemporaryCreationError::LimitReached) => return Ok(None),
+                        Ok(None) | Err(TemporaryCreationError::Creation) => {}
                     }
                 },
                 AggregatedMerchantFallbackStrategy::Skip => {
@@ -421,6 +706,56 @@ impl WaveAggregatedMerchantResolver {
     }
 }
 
+/// Used when `connectors.wave.max_concurrent_temporary_merchants` is not configured
+const WAVE_DEFAULT_MAX_CONCURRENT_TEMPORARY_MERCHANTS: usize = 3;
+
+/// In-process guard on concurrent aggregated merchant creations, so a burst of payments with a
+/// broken primary configuration cannot create an unbounded number of merchants on Wave. The cap
+/// is process-wide, so it comes from the connector config rather than any one merchant's metadata
+#[derive(Debug)]
+pub struct WaveCreationLimiter {
+    in_flight: AtomicUsize,
+    max_in_flight: usize,
+}
+
+/// Slot held while a creation is in flight, released on drop
+#[derive(Debug)]
+pub struct WaveCreationPermit<'a> {
+    limiter: &'a WaveCreationLimiter,
+}
+
+impl WaveCreationLimiter {
+    pub const fn new(max_in_flight: usize) -> Self {
+        Self {
+            in_flight: AtomicUsize::new(0),
+            max_in_flight,
+        }
+    }
+
+    /// Take a slot unless `max_in_flight` creations are already running
+    pub fn try_acquire(&self) -> Option<WaveCreationPermit<'_>> {
+        self.in_flight
+            .fetch_update(Ordering::AcqRel, Ordering::Acquire, |in_flight| {
+                (in_flight < self.max_in_flight).then_some(in_flight + 1)
+            })
+            .ok()
+            .map(|_| WaveCreationPermit { limiter: self })
+    }
+}
+
+impl Drop for WaveCreationPermit<'_> {
+    fn drop(&mut self) {
+        self.limiter.in_flight.fetch_sub(1, Ordering::AcqRel);
+    }
+}
+
+/// Why a temporary aggregated merchant was not created
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TemporaryCreationError {
+    LimitReached,
+    Creation,
+}
+
 /// Fallback strategies for aggregated merchant resolution
 #[derive(Debug, Clone)]
 pub enum AggregatedMerchantFallbackStrategy {
@@ -429,8 +764,72 @@ pub enum AggregatedMerchantFallbackStrategy {
     Skip,
 }
 
-impl ConnectorSpecifications for Wave {}
-impl ConnectorValidation for Wave {}
+static WAVE_SUPPORTED_PAYMENT_METHODS: LazyLock<SupportedPaymentMethods> = LazyLock::new(|| {
+    // Checkout sessions are paid in one step, there is no separate capture
+    let supported_capture_methods = vec![enums::CaptureMethod::Automatic];
+
+    let mut wave_supported_payment_methods = SupportedPaymentMethods::new();
+
+    // Supported currencies (XOF) are advertised through `pm_filters.wave`
+    wave_supported_payment_methods.add(
+        enums::PaymentMethod::Wallet,
+        enums::PaymentMethodType::MobilePay,
+        PaymentMethodDetails {
+            mandates: enums::FeatureStatus::NotSupported,
+            refunds: enums::FeatureStatus::Supported,
+            supported_capture_methods: supported_capture_methods.clone(),
+            specific_features: None,
+        },
+    );
+
+    wave_supported_payment_methods.add(
+        enums::PaymentMethod::MobilePayment,
+        enums::PaymentMethodType::DirectCarrierBilling,
+        PaymentMethodDetails {
+            mandates: enums::FeatureStatus::NotSupported,
+            refunds: enums::FeatureStatus::Supported,
+            supported_capture_methods,
+            specific_features: None,
+        },
+    );
+
+    wave_supported_payment_methods
+});
+
+static WAVE_CONNECTOR_INFO: ConnectorInfo = ConnectorInfo {
+    display_name: "Wave",
+    description: "Wave is a mobile money provider in West Africa, accepting XOF payments from Wave wallets through hosted checkout sessions",
+    connector_type: enums::HyperswitchConnectorCategory::PaymentGateway,
+    integration_status: enums::ConnectorIntegrationStatus::Alpha,
+};
+
+static WAVE_SUPPORTED_WEBHOOK_FLOWS: [enums::EventClass; 2] =
+    [enums::EventClass::Payments, enums::EventClass::Refunds];
+
+impl ConnectorSpecifications for Wave {
+    fn get_connector_about(&self) -> Option<&'static ConnectorInfo> {
+        Some(&WAVE_CONNECTOR_INFO)
+    }
+
+    fn get_supported_payment_methods(&self) -> Option<&'static SupportedPaymentMethods> {
+        Some(&*WAVE_SUPPORTED_PAYMENT_METHODS)
+    }
+
+    fn get_supported_webhook_flows(&self) -> Option<&'static [enums::EventClass]> {
+        Some(&WAVE_SUPPORTED_WEBHOOK_FLOWS)
+    }
+}
+impl ConnectorValidation for Wave {
+    fn validate_authorize_amount(
+        &self,
+        amount: MinorUnit,
+        connector_meta_data: Option<SecretSerdeValue>,
+    ) -> CustomResult<(), errors::ConnectorError> {
+        let metadata = wave::parse_wave_connector_metadata(connector_meta_data.as_ref());
+        wave::validate_wave_amount_limits(amount, metadata.as_ref())
+            .map_err(|error| errors::ConnectorError::from(error).into())
+    }
+}
 
 // Core trait implementations
 impl api::Payment for Wave {}
@@ -444,14 +843,147 @@ impl api::PaymentVoid for Wave {}
 impl api::Refund for Wave {}
 impl api::RefundExecute for Wave {}
 impl api::RefundSync for Wave {}
+impl api::PaymentsPreProcessing for Wave {}
 
 // Default implementations for required ConnectorIntegration traits
-impl ConnectorIntegration<Session, PaymentsSessionData, PaymentsResponseData> for Wave {}
-impl ConnectorIntegration<SetupMandate, SetupMandateRequestData, PaymentsResponseData> for Wave {}
-impl ConnectorIntegration<PaymentMethodToken, PaymentMethodTokenizationData, PaymentsResponseData> for Wave {}
+// Wave pays through its hosted checkout only, there are no client-side sessions or tokens
+impl ConnectorIntegration<Session, PaymentsSessionData, PaymentsResponseData> for Wave {
+    fn build_request(
+        &self,
+        _req: &PaymentsSessionRouterData,
+        _connectors: &Connectors,
+    ) -> CustomResult<Option<Request>, errors::ConnectorError> {
+        Err(errors::ConnectorError::NotImplemented("Session flow".to_string()).into())
+    }
+}
+
+impl ConnectorIntegration<PaymentMethodToken, PaymentMethodTokenizationData, PaymentsResponseData> for Wave {
+    fn build_request(
+        &self,
+        _req: &TokenizationRouterData,
+        _connectors: &Connectors,
+    ) -> CustomResult<Option<Request>, errors::ConnectorError> {
+        Err(errors::ConnectorError::NotImplemented("Payment Method Token flow".to_string()).into())
+    }
+}
+
 impl ConnectorIntegration<AccessTokenAuth, AccessTokenRequestData, hyperswitch_domain_models::router_data::AccessToken> for Wave {}
 
+// Wave has no recurring payment or mandate concept, so reject the flow explicitly
+impl ConnectorIntegration<SetupMandate, SetupMandateRequestData, PaymentsResponseData> for Wave {
+    fn build_request(
+        &self,
+        _req: &SetupMandateRouterData,
+        _connectors: &Connectors,
+    ) -> CustomResult<Option<Request>, errors::ConnectorError> {
+        Err(errors::ConnectorError::NotSupported {
+            message: "Setup Mandate flow".to_string(),
+            connector: "wave",
+        }
+        .into())
+    }
+}
+
+/// Correlation header Wave support can use to match our requests to their logs.
+/// Derived from the attempt or refund reference so retries of the same logical request share it.
+fn get_request_id_header(reference: &str) -> (String, Maskable<String>) {
+    (headers::X_REQUEST_ID.to_string(), reference.to_string().into())
+}
+
+/// Correlation id for the raw service calls, which have no attempt to derive one from
+fn generate_wave_request_id() -> String {
+    let request_id = uuid::Uuid::new_v4().to_string();
+    router_env::logger::debug!(wave_request_id = %request_id, "Sending Wave service request");
+    request_id
+}
+
 // Payment flow implementations
+/// Looks the payment's aggregated merchant up on Wave before authorize, so the checkout session is
+/// only created under a merchant Wave reports active
+impl ConnectorIntegration<PreProcessing, PaymentsPreProcessingData, PaymentsResponseData> for Wave {
+    fn get_headers(
+        &self,
+        req: &PaymentsPreProcessingRouterData,
+        _connectors: &Connectors,
+    ) -> CustomResult<Vec<(String, Maskable<String>)>, errors::ConnectorError> {
+        self.build_common_headers(&req.connector_auth_type, false)
+    }
+
+    fn get_url(
+        &self,
+        req: &PaymentsPreProcessingRouterData,
+        connectors: &Connectors,
+    ) -> CustomResult<String, errors::ConnectorError> {
+        let aggregated_merchant_id = wave::get_aggregated_merchant_to_confirm(req)?.ok_or(
+            errors::ConnectorError::MissingRequiredField {
+                field_name: "aggregated_merchant_id",
+            },
+        )?;
+        Ok(format!(
+            "{}{}",
+            connectors.wave.get_merchant_service_base_url(),
+            build_wave_url(WAVE_AGGREGATED_MERCHANT_BY_ID, &[("id", &aggregated_merchant_id)])?
+        ))
+    }
+
+    #[instrument(skip_all, fields(flow = "pre_processing"))]
+    fn build_request(
+        &self,
+        req: &PaymentsPreProcessingRouterData,
+        connectors: &Connectors,
+    ) -> CustomResult<Option<Request>, errors::ConnectorError> {
+        // Nothing to look up, authorize goes ahead without an aggregated merchant
+        if wave::get_aggregated_merchant_to_confirm(req)?.is_none() {
+            return Ok(None);
+        }
+        let request = RequestBuilder::new()
+            .method(Method::Get)
+            .url(&self.get_url(req, connectors)?)
+            .attach_default_headers()
+            .headers(self.get_headers(req, connectors)?)
+            .build();
+        Ok(Some(request))
+    }
+
+    #[instrument(skip_all, fields(flow = "pre_processing", http_status = tracing::field::Empty))]
+    fn handle_response(
+        &self,
+        data: &PaymentsPreProcessingRouterData,
+        event_builder: Option<&mut ConnectorEvent>,
+        res: Response,
+    ) -> CustomResult<PaymentsPreProcessingRouterData, errors::ConnectorError> {
+        tracing::Span::current().record("http_status", res.status_code);
+        let response: wave::WaveAggregatedMerchant = res
+            .response
+            .parse_struct("WaveAggregatedMerchant")
+            .change_context(errors::ConnectorError::ResponseDeserializationFailed)?;
+        event_builder.map(|event| event.set_response_body(&response));
+        let router_data = RouterData::try_from(ResponseRouterData {
+            response,
+            data: data.clone(),
+            http_code: res.status_code,
+        });
+        record_wave_flow_response("pre_processing", &router_data);
+        router_data
+    }
+
+    fn get_error_response(
+        &self,
+        res: Response,
+        event_builder: Option<&mut ConnectorEvent>,
+    ) -> CustomResult<ErrorResponse, errors::ConnectorError> {
+        self.build_flow_error_response("pre_processing", res, event_builder)
+    }
+
+    fn get_5xx_error_response(
+        &self,
+        res: Response,
+        event_builder: Option<&mut ConnectorEvent>,
+    ) -> CustomResult<ErrorResponse, errors::ConnectorError> {
+        self.build_flow_5xx_error_response("pre_processing", res, event_builder)
+    }
+}
+
 impl PaymentAuthorize for Wave {}
 
 impl ConnectorIntegration<Authorize, PaymentsAuthorizeData, PaymentsResponseData> for Wave {
@@ -460,12 +992,11 @@ impl ConnectorIntegration<Authorize, PaymentsAuthorizeData, PaymentsResponseData
         req: &PaymentsAuthorizeRouterData,
         _connectors: &Connectors,
     ) -> CustomResult<Vec<(String, Maskable<String>)>, errors::ConnectorError> {
-        let mut headers_vec = vec![(
-            headers::CONTENT_TYPE.to_string(),
-            PaymentsAuthorizeType::get_content_type(self).to_string().into(),
-        )];
-        let mut auth = self.get_auth_header(&req.connector_auth_type)?;
-        headers_vec.append(&mut auth);
+        let reference = wave::get_wave_checkout_reference(req);
+        let mut headers_vec = self.build_common_headers(&req.connector_auth_type, true)?;
+        headers_vec.push(get_request_id_header(&reference));
+        // Same key on every router retry of the attempt, so Wave never creates a second session for it
+        headers_vec.push((headers::IDEMPOTENCY_KEY.to_string(), reference.into()));
         Ok(headers_vec)
     }
 
@@ -489,44 +1020,18 @@ impl ConnectorIntegration<Authorize, PaymentsAuthorizeData, PaymentsResponseData
             req,
         ))?;
         
-        // Create the checkout session request with aggregated merchant support
-        let mut connector_req = wave::WaveCheckoutSessionRequest::try_from(&connector_router_data)?;
-        
-        // If aggregated merchant ID is not already set, try to resolve it
-        if connector_req.aggregated_merchant_id.is_none() {
-            let auth = wave::WaveAuthType::try_from(&req.connector_auth_type)?;
-            
-            // Only resolve if aggregated merchants are enabled
-            if auth.aggregated_merchants_enabled {
-                // Try to resolve aggregated merchant from metadata
-                // Note: In a real implementation, this might need async resolution
-                let metadata = wave::extract_wave_connector_metadata(req)?;
-                if let Some(meta) = metadata {
-                    if let Some(ref merchant_id) = meta.aggregated_merchant_id {
-                        connector_req.aggregated_merchant_id = Some(merchant_id.clone());
-                        
-                        router_env::logger::info!(
-                            "Using configured aggregated merchant: {} for payment",
-                            merchant_id
-                        );
-                    }
-                }
-            }
-        }
-        
+        // The aggregated merchant was looked up on Wave by the PreProcessing flow
+        let connector_req = wave::WaveCheckoutSessionRequest::try_from(&connector_router_data)?;
+        wave::reconcile_wave_checkout_amount(&connector_req, req.request.minor_amount)?;
         Ok(RequestContent::Json(Box::new(connector_req)))
     }
 
+    #[instrument(skip_all, fields(flow = "authorize"))]
     fn build_request(
         &self,
         req: &PaymentsAuthorizeRouterData,
         connectors: &Connectors,
     ) -> CustomResult<Option<Request>, errors::ConnectorError> {
-        // Note: This is a synchronous method, but aggregated merchant resolution is async.
-        // In a real production implementation, the aggregated merchant resolution should be 
-        // moved to an earlier async phase in the payment processing pipeline.
-        // For now, we rely on pre-configured aggregated merchant IDs in metadata.
-        
         let request = RequestBuilder::new()
             .method(Method::Post)
             .url(&self.get_url(req, connectors)?)
@@ -537,23 +1042,45 @@ impl ConnectorIntegration<Authorize, PaymentsAuthorizeData, PaymentsResponseData
         Ok(Some(request))
     }
 
+    #[instrument(skip_all, fields(flow = "authorize", http_status = tracing::field::Empty))]
     fn handle_response(
         &self,
         data: &PaymentsAuthorizeRouterData,
         event_builder: Option<&mut ConnectorEvent>,
         res: Response,
     ) -> CustomResult<PaymentsAuthorizeRouterData, errors::ConnectorError> {
+        tracing::Span::current().record("http_status", res.status_code);
         let response: WaveCheckoutSessionResponse = res
             .response
             .parse_struct("WaveCheckoutSessionResponse")
             .change_context(errors::ConnectorError::ResponseDeserializationFailed)?;
 
-        event_builder.map(|i| i.set_response_body(&response));
-        <PaymentsAuthorizeRouterData as TryFrom<ResponseRouterData<Authorize, WaveCheckoutSessionResponse, PaymentsAuthorizeData, PaymentsResponseData>>>::try_from(ResponseRouterData {
+        // Nothing to compare when no reference was sent
+        let reference_mismatch = wave::get_wave_request_reference(data).and_then(|expected| {
+            wave::check_reference_echo(&expected, response.reference.as_deref())
+        });
+        let amount_mismatch =
+            wave::check_checkout_amount(data.request.minor_amount, data.request.currency, &response);
+        if let Some(event) = event_builder {
+            event.set_response_body(&response);
+            let mut discrepancies = serde_json::Map::new();
+            if let Some(mismatch) = reference_mismatch {
+                discrepancies.insert("reference_mismatch".to_string(), serde_json::json!(mismatch));
+            }
+            if let Some(mismatch) = amount_mismatch {
+                discrepancies.insert("amount_mismatch".to_string(), serde_json::json!(mismatch));
+            }
+            if !discrepancies.is_empty() {
+                event.set_error(serde_json::Value::Object(discrepancies));
+            }
+        }
+        let router_data = <PaymentsAuthorizeRouterData as TryFrom<ResponseRouterData<Authorize, WaveCheckoutSessionResponse, PaymentsAuthorizeData, PaymentsResponseData>>>::try_from(ResponseRouterData {
             response,
             data: data.clone(),
             http_code: res.status_code,
-        })
+        });
+        record_wave_flow_response("authorize", &router_data);
+        router_data
     }
 
     fn get_error_response(
@@ -561,7 +1088,7 @@ impl ConnectorIntegration<Authorize, PaymentsAuthorizeData, PaymentsResponseData
         res: Response,
         event_builder: Option<&mut ConnectorEvent>,
     ) -> CustomResult<ErrorResponse, errors::ConnectorError> {
-        self.build_error_response(res, event_builder)
+        self.build_flow_error_response("authorize", res, event_builder)
     }
 
     fn get_5xx_error_response(
@@ -569,10 +1096,21 @@ impl ConnectorIntegration<Authorize, PaymentsAuthorizeData, PaymentsResponseData
         res: Response,
         event_builder: Option<&mut ConnectorEvent>,
     ) -> CustomResult<ErrorResponse, errors::ConnectorError> {
-        self.build_error_response(res, event_builder)
+        self.build_flow_5xx_error_response("authorize", res, event_builder)
     }
 }
 
+/// Reference to look the checkout session up by when PSync has no Wave session id, e.g. when
+/// recovering a payment whose authorize response was lost
+fn get_psync_lookup_reference(req: &PaymentsSyncRouterData) -> Option<String> {
+    req.request
+        .connector_transaction_id
+        .get_connector_transaction_id()
+        .is_err()
+        .then(|| wave::get_wave_request_reference(req))
+        .flatten()
+}
+
 // Payment Sync implementation
 impl ConnectorIntegration<PSync, PaymentsSyncData, PaymentsResponseData> for Wave {
     fn get_headers(
@@ -580,9 +1118,8 @@ impl ConnectorIntegration<PSync, PaymentsSyncData, PaymentsResponseData> for Wav
         req: &PaymentsSyncRouterData,
         _connectors: &Connectors,
     ) -> CustomResult<Vec<(String, Maskable<String>)>, errors::ConnectorError> {
-        let mut headers_vec = vec![("Accept".to_string(), "application/json".to_string().into())];
-        let mut auth = self.get_auth_header(&req.connector_auth_type)?;
-        headers_vec.append(&mut auth);
+        let mut headers_vec = self.build_common_headers(&req.connector_auth_type, false)?;
+        headers_vec.push(get_request_id_header(&req.connector_request_reference_id));
         Ok(headers_vec)
     }
 
@@ -591,6 +1128,14 @@ impl ConnectorIntegration<PSync, PaymentsSyncData, PaymentsResponseData> for Wav
         req: &PaymentsSyncRouterData,
         connectors: &Connectors,
     ) -> CustomResult<String, errors::ConnectorError> {
+        // Without a session id, fall back to searching by the reference sent at checkout
+        if let Some(reference) = get_psync_lookup_reference(req) {
+            return Ok(format!(
+                "{}{}",
+                self.base_url(connectors),
+                build_wave_url(WAVE_CHECKOUT_SESSION_SEARCH, &[("reference", &reference)])?
+            ));
+        }
         let connector_payment_id = req
             .request
             .connector_transaction_id
@@ -600,10 +1145,11 @@ impl ConnectorIntegration<PSync, PaymentsSyncData, PaymentsResponseData> for Wav
         Ok(format!(
             "{}{}",
             self.base_url(connectors),
-            WAVE_CHECKOUT_SESSION_STATUS.replace("{session_id}", &connector_payment_id)
+            build_wave_url(WAVE_CHECKOUT_SESSION_STATUS, &[("session_id", wave::WaveSessionId::new(connector_payment_id)?.as_ref())])?
         ))
     }
 
+    #[instrument(skip_all, fields(flow = "psync"))]
     fn build_request(
         &self,
         req: &PaymentsSyncRouterData,
@@ -619,23 +1165,49 @@ impl ConnectorIntegration<PSync, PaymentsSyncData, PaymentsResponseData> for Wav
         ))
     }
 
+    #[instrument(skip_all, fields(flow = "psync", http_status = tracing::field::Empty))]
     fn handle_response(
         &self,
         data: &PaymentsSyncRouterData,
         event_builder: Option<&mut ConnectorEvent>,
         res: Response,
     ) -> CustomResult<PaymentsSyncRouterData, errors::ConnectorError> {
-        let response: wave::WavePaymentStatusResponse = res
-            .response
-            .parse_struct("WavePaymentStatusResponse")
-            .change_context(errors::ConnectorError::ResponseDeserializationFailed)?;
+        tracing::Span::current().record("http_status", res.status_code);
+        let response: wave::WavePaymentStatusResponse = match get_psync_lookup_reference(data) {
+            None => res
+                .response
+                .parse_struct("WavePaymentStatusResponse")
+                .change_context(errors::ConnectorError::ResponseDeserializationFailed)?,
+            Some(reference) => res
+                .response
+                .parse_struct::<wave::WaveCheckoutSessionSearchResponse>("WaveCheckoutSessionSearchResponse")
+                .change_context(errors::ConnectorError::ResponseDeserializationFailed)?
+                .into_latest_for_reference(&reference)
+                .ok_or_else(|| {
+                    error_stack::report!(errors::ConnectorError::MissingConnectorTransactionID)
+                        .attach_printable(format!("No Wave checkout session found for reference {reference}"))
+                })?,
+        };
 
-        event_builder.map(|i| i.set_response_body(&response));
-        <PaymentsSyncRouterData as TryFrom<ResponseRouterData<PSync, wave::WavePaymentStatusResponse, PaymentsSyncData, PaymentsResponseData>>>::try_from(ResponseRouterData {
+        if let Some(event) = event_builder {
+            event.set_response_body(&response);
+        }
+
+        let connector_router_data = wave::WaveRouterData::try_from((
+            &self.get_currency_unit(),
+            data.request.currency,
+            data.request.amount,
+            data,
+        ))?;
+        wave::reconcile_synced_amount(&connector_router_data, &response);
+
+        let router_data = <PaymentsSyncRouterData as TryFrom<ResponseRouterData<PSync, wave::WavePaymentStatusResponse, PaymentsSyncData, PaymentsResponseData>>>::try_from(ResponseRouterData {
             response,
             data: data.clone(),
             http_code: res.status_code,
-        })
+        });
+        record_wave_flow_response("psync", &router_data);
+        router_data
     }
 
     fn get_error_response(
@@ -643,7 +1215,7 @@ impl ConnectorIntegration<PSync, PaymentsSyncData, PaymentsResponseData> for Wav
         res: Response,
         event_builder: Option<&mut ConnectorEvent>,
     ) -> CustomResult<ErrorResponse, errors::ConnectorError> {
-        self.build_error_response(res, event_builder)
+        self.build_flow_error_response("psync", res, event_builder)
     }
 
     fn get_5xx_error_response(
@@ -651,7 +1223,7 @@ impl ConnectorIntegration<PSync, PaymentsSyncData, PaymentsResponseData> for Wav
         res: Response,
         event_builder: Option<&mut ConnectorEvent>,
     ) -> CustomResult<ErrorResponse, errors::ConnectorError> {
-        self.build_error_response(res, event_builder)
+        self.build_flow_5xx_error_response("psync", res, event_builder)
     }
 }
 
@@ -706,9 +1278,8 @@ impl ConnectorIntegration<Void, PaymentsCancelData, PaymentsResponseData> for Wa
         req: &PaymentsCancelRouterData,
         _connectors: &Connectors,
     ) -> CustomResult<Vec<(String, Maskable<String>)>, errors::ConnectorError> {
-        let mut headers_vec = vec![("Accept".to_string(), "application/json".to_string().into())];
-        let mut auth = self.get_auth_header(&req.connector_auth_type)?;
-        headers_vec.append(&mut auth);
+        let mut headers_vec = self.build_common_headers(&req.connector_auth_type, false)?;
+        headers_vec.push(get_request_id_header(&req.connector_request_reference_id));
         Ok(headers_vec)
     }
 
@@ -721,7 +1292,7 @@ impl ConnectorIntegration<Void, PaymentsCancelData, PaymentsResponseData> for Wa
         Ok(format!(
             "{}{}",
             self.base_url(connectors),
-            WAVE_CANCEL_PAYMENT.replace("{txn_id}", &connector_payment_id)
+            build_wave_url(WAVE_CANCEL_PAYMENT, &[("txn_id", wave::WaveTransactionId::new(connector_payment_id)?.as_ref())])?
         ))
     }
 
@@ -740,11 +1311,19 @@ impl ConnectorIntegration<Void, PaymentsCancelData, PaymentsResponseData> for Wa
         Ok(RequestContent::Json(Box::new(connector_req)))
     }
 
+    #[instrument(skip_all, fields(flow = "void"))]
     fn build_request(
         &self,
         req: &PaymentsCancelRouterData,
         connectors: &Connectors,
     ) -> CustomResult<Option<Request>, errors::ConnectorError> {
+        // Wave only cancels sessions the payer has not finished, so fail here instead of on an opaque API error
+        if req.status.is_terminal_status() {
+            return Err(error_stack::report!(errors::ConnectorError::InvalidDataFormat {
+                field_name: "status",
+            })
+            .attach_printable(format!("payment cannot be voided in status {}", req.status)));
+        }
         let request = RequestBuilder::new()
             .method(Method::Post)
             .url(&self.get_url(req, connectors)?)
@@ -755,23 +1334,29 @@ impl ConnectorIntegration<Void, PaymentsCancelData, PaymentsResponseData> for Wa
         Ok(Some(request))
     }
 
+    #[instrument(skip_all, fields(flow = "void", http_status = tracing::field::Empty))]
     fn handle_response(
         &self,
         data: &PaymentsCancelRouterData,
         event_builder: Option<&mut ConnectorEvent>,
         res: Response,
     ) -> CustomResult<PaymentsCancelRouterData, errors::ConnectorError> {
+        tracing::Span::current().record("http_status", res.status_code);
         let response: wave::WavePaymentsCancelResponse = res
             .response
             .parse_struct("WavePaymentsCancelResponse")
             .change_context(errors::ConnectorError::ResponseDeserializationFailed)?;
 
-        event_builder.map(|i| i.set_response_body(&response));
-        <PaymentsCancelRouterData as TryFrom<ResponseRouterData<Void, wave::WavePaymentsCancelResponse, PaymentsCancelData, PaymentsResponseData>>>::try_from(ResponseRouterData {
+        if let Some(event) = event_builder {
+            event.set_response_body(&response);
+        }
+        let router_data = <PaymentsCancelRouterData as TryFrom<ResponseRouterData<Void, wave::WavePaymentsCancelResponse, PaymentsCancelData, PaymentsResponseData>>>::try_from(ResponseRouterData {
             response,
             data: data.clone(),
             http_code: res.status_code,
-        })
+        });
+        record_wave_flow_response("void", &router_data);
+        router_data
     }
 
     fn get_error_response(
@@ -779,7 +1364,7 @@ impl ConnectorIntegration<Void, PaymentsCancelData, PaymentsResponseData> for Wa
         res: Response,
         event_builder: Option<&mut ConnectorEvent>,
     ) -> CustomResult<ErrorResponse, errors::ConnectorError> {
-        self.build_error_response(res, event_builder)
+        self.build_flow_error_response("void", res, event_builder)
     }
 
     fn get_5xx_error_response(
@@ -787,7 +1372,7 @@ impl ConnectorIntegration<Void, PaymentsCancelData, PaymentsResponseData> for Wa
         res: Response,
         event_builder: Option<&mut ConnectorEvent>,
     ) -> CustomResult<ErrorResponse, errors::ConnectorError> {
-        self.build_error_response(res, event_builder)
+        self.build_flow_5xx_error_response("void", res, event_builder)
     }
 }
 
@@ -798,12 +1383,8 @@ impl ConnectorIntegration<Execute, RefundsData, RefundsResponseData> for Wave {
         req: &RefundsRouterData<Execute>,
         _connectors: &Connectors,
     ) -> CustomResult<Vec<(String, Maskable<String>)>, errors::ConnectorError> {
-        let mut headers_vec = vec![(
-            headers::CONTENT_TYPE.to_string(),
-            RefundExecuteType::get_content_type(self).to_string().into(),
-        )];
-        let mut auth = self.get_auth_header(&req.connector_auth_type)?;
-        headers_vec.append(&mut auth);
+        let mut headers_vec = self.build_common_headers(&req.connector_auth_type, true)?;
+        headers_vec.push(get_request_id_header(&req.request.refund_id));
         Ok(headers_vec)
     }
 
@@ -816,7 +1397,7 @@ impl ConnectorIntegration<Execute, RefundsData, RefundsResponseData> for Wave {
         Ok(format!(
             "{}{}",
             self.base_url(connectors),
-            WAVE_REFUND_FOR_TXN.replace("{txn_id}", &connector_payment_id)
+            build_wave_url(WAVE_REFUND_FOR_TXN, &[("txn_id", wave::WaveTransactionId::new(connector_payment_id)?.as_ref())])?
         ))
     }
 
@@ -835,6 +1416,7 @@ impl ConnectorIntegration<Execute, RefundsData, RefundsResponseData> for Wave {
         Ok(RequestContent::Json(Box::new(connector_req)))
     }
 
+    #[instrument(skip_all, fields(flow = "refund"))]
     fn build_request(
         &self,
         req: &RefundsRouterData<Execute>,
@@ -850,23 +1432,29 @@ impl ConnectorIntegration<Execute, RefundsData, RefundsResponseData> for Wave {
         Ok(Some(request))
     }
 
+    #[instrument(skip_all, fields(flow = "refund", http_status = tracing::field::Empty))]
     fn handle_response(
         &self,
         data: &RefundsRouterData<Execute>,
         event_builder: Option<&mut ConnectorEvent>,
         res: Response,
     ) -> CustomResult<RefundsRouterData<Execute>, errors::ConnectorError> {
+        tracing::Span::current().record("http_status", res.status_code);
         let response: wave::WaveRefundResponse = res
             .response
             .parse_struct("WaveRefundResponse")
             .change_context(errors::ConnectorError::ResponseDeserializationFailed)?;
 
-        event_builder.map(|i| i.set_response_body(&response));
-        <RefundsRouterData<Execute> as TryFrom<crate::types::RefundsResponseRouterData<Execute, wave::WaveRefundResponse>>>::try_from(crate::types::RefundsResponseRouterData {
+        if let Some(event) = event_builder {
+            event.set_response_body(&response);
+        }
+        let router_data = <RefundsRouterData<Execute> as TryFrom<crate::types::RefundsResponseRouterData<Execute, wave::WaveRefundResponse>>>::try_from(crate::types::RefundsResponseRouterData {
             response,
             data: data.clone(),
             http_code: res.status_code,
-        })
+        });
+        record_wave_flow_response("refund", &router_data);
+        router_data
     }
 
     fn get_error_response(
@@ -874,7 +1462,7 @@ impl ConnectorIntegration<Execute, RefundsData, RefundsResponseData> for Wave {
         res: Response,
         event_builder: Option<&mut ConnectorEvent>,
     ) -> CustomResult<ErrorResponse, errors::ConnectorError> {
-        self.build_error_response(res, event_builder)
+        self.build_flow_error_response("refund", res, event_builder)
     }
 
     fn get_5xx_error_response(
@@ -882,7 +1470,7 @@ impl ConnectorIntegration<Execute, RefundsData, RefundsResponseData> for Wave {
         res: Response,
         event_builder: Option<&mut ConnectorEvent>,
     ) -> CustomResult<ErrorResponse, errors::ConnectorError> {
-        self.build_error_response(res, event_builder)
+        self.build_flow_5xx_error_response("refund", res, event_builder)
     }
 }
 
@@ -893,9 +1481,8 @@ impl ConnectorIntegration<RSync, RefundsData, RefundsResponseData> for Wave {
         req: &RefundSyncRouterData,
         _connectors: &Connectors,
     ) -> CustomResult<Vec<(String, Maskable<String>)>, errors::ConnectorError> {
-        let mut headers_vec = vec![("Accept".to_string(), "application/json".to_string().into())];
-        let mut auth = self.get_auth_header(&req.connector_auth_type)?;
-        headers_vec.append(&mut auth);
+        let mut headers_vec = self.build_common_headers(&req.connector_auth_type, false)?;
+        headers_vec.push(get_request_id_header(&req.request.refund_id));
         Ok(headers_vec)
     }
 
@@ -908,10 +1495,11 @@ impl ConnectorIntegration<RSync, RefundsData, RefundsResponseData> for Wave {
         Ok(format!(
             "{}{}",
             self.base_url(connectors),
-            WAVE_REFUND_STATUS.replace("{refund_id}", &connector_refund_id)
+            build_wave_url(WAVE_REFUND_STATUS, &[("refund_id", wave::WaveRefundId::new(connector_refund_id)?.as_ref())])?
         ))
     }
 
+    #[instrument(skip_all, fields(flow = "refund_sync"))]
     fn build_request(
         &self,
         req: &RefundSyncRouterData,
@@ -927,23 +1515,29 @@ impl ConnectorIntegration<RSync, RefundsData, RefundsResponseData> for Wave {
         ))
     }
 
+    #[instrument(skip_all, fields(flow = "refund_sync", http_status = tracing::field::Empty))]
     fn handle_response(
         &self,
         data: &RefundSyncRouterData,
         event_builder: Option<&mut ConnectorEvent>,
         res: Response,
     ) -> CustomResult<RefundSyncRouterData, errors::ConnectorError> {
+        tracing::Span::current().record("http_status", res.status_code);
         let response: wave::WaveRefundResponse = res
             .response
             .parse_struct("WaveRefundResponse")
             .change_context(errors::ConnectorError::ResponseDeserializationFailed)?;
 
-        event_builder.map(|i| i.set_response_body(&response));
-        <RefundSyncRouterData as TryFrom<crate::types::RefundsResponseRouterData<RSync, wave::WaveRefundResponse>>>::try_from(crate::types::RefundsResponseRouterData {
+        if let Some(event) = event_builder {
+            event.set_response_body(&response);
+        }
+        let router_data = <RefundSyncRouterData as TryFrom<crate::types::RefundsResponseRouterData<RSync, wave::WaveRefundResponse>>>::try_from(crate::types::RefundsResponseRouterData {
             response,
             data: data.clone(),
             http_code: res.status_code,
-        })
+        });
+        record_wave_flow_response("refund_sync", &router_data);
+        router_data
     }
 
     fn get_error_response(
@@ -951,7 +1545,7 @@ impl ConnectorIntegration<RSync, RefundsData, RefundsResponseData> for Wave {
         res: Response,
         event_builder: Option<&mut ConnectorEvent>,
     ) -> CustomResult<ErrorResponse, errors::ConnectorError> {
-        self.build_error_response(res, event_builder)
+        self.build_flow_error_response("refund_sync", res, event_builder)
     }
 
     fn get_5xx_error_response(
@@ -959,7 +1553,7 @@ impl ConnectorIntegration<RSync, RefundsData, RefundsResponseData> for Wave {
         res: Response,
         event_builder: Option<&mut ConnectorEvent>,
     ) -> CustomResult<ErrorResponse, errors::ConnectorError> {
-        self.build_error_response(res, event_builder)
+        self.build_flow_5xx_error_response("refund_sync", res, event_builder)
     }
 }
 
@@ -969,26 +1563,253 @@ impl ConnectorIntegration<RSync, RefundsData, RefundsResponseData> for Wave {
 
 
 
+const WAVE_SIGNATURE_HEADER: &str = "Wave-Signature";
+/// Maximum age, in seconds, of a signed webhook before it is treated as a replay
+const WAVE_WEBHOOK_TOLERANCE_SECONDS: i64 = 300;
+
+#[async_trait::async_trait]
 impl IncomingWebhook for Wave {
+    async fn verify_webhook_source(
+        &self,
+        request: &IncomingWebhookRequestDetails<'_>,
+        _merchant_id: &id_type::MerchantId,
+        _connector_webhook_details: Option<SecretSerdeValue>,
+        connector_account_details: crypto::Encryptable<Secret<serde_json::Value>>,
+        _connector_name: &str,
+    ) -> CustomResult<bool, errors::ConnectorError> {
+        let connector_auth_type: ConnectorAuthType = connector_account_details
+            .parse_value::<ConnectorAuthType>("ConnectorAuthType")
+            .change_context_lazy(|| errors::ConnectorError::WebhookSourceVerificationFailed)?;
+        let auth = wave::WaveAuthType::try_from(&connector_auth_type)
+            .change_context(errors::ConnectorError::WebhookSourceVerificationFailed)?;
+        // Accounts on HeaderKey/BodyKey have no webhook secret; unverified webhooks fall back to psync
+        let Some(webhook_secret) = auth.webhook_secret else {
+            router_env::logger::debug!("Wave webhook received for an account without a webhook secret");
+            return Ok(false);
+        };
+
+        let header = crate::utils::get_header_key_value(WAVE_SIGNATURE_HEADER, request.headers)
+            .change_context(errors::ConnectorError::WebhookSignatureNotFound)?;
+        let signature = wave::WaveWebhookSignature::parse(header)
+            .ok_or(errors::ConnectorError::WebhookSignatureNotFound)?;
+        if !signature.is_within_tolerance(
+            date_time::now_unix_timestamp(),
+            WAVE_WEBHOOK_TOLERANCE_SECONDS,
+        ) {
+            router_env::logger::warn!("Wave webhook signature timestamp is outside the tolerance window");
+            return Ok(false);
+        }
+        let message = signature.signed_message(request.body);
+
+        for candidate in &signature.signatures {
+            if crypto::HmacSha256
+                .verify_signature(webhook_secret.peek().as_bytes(), candidate, &message)
+                .change_context(errors::ConnectorError::WebhookSourceVerificationFailed)?
+            {
+                return Ok(true);
+            }
+        }
+        Ok(false)
+    }
+
     fn get_webhook_object_reference_id(
         &self,
-        _request: &IncomingWebhookRequestDetails<'_>,
+        request: &IncomingWebhookRequestDetails<'_>,
     ) -> CustomResult<ObjectReferenceId, errors::ConnectorError> {
-        Err(errors::ConnectorError::WebhooksNotImplemented.into())
+        let webhook: wave::WaveWebhookBody = request
+            .body
+            .parse_struct("WaveWebhookBody")
+            .change_context(errors::ConnectorError::WebhookReferenceIdNotFound)?;
+        // Refund events carry the refund, checkout events the session, so the reference type follows the event
+        webhook
+            .event_type
+            .object_reference(&webhook.data.id)
+            .ok_or_else(|| errors::ConnectorError::WebhookReferenceIdNotFound.into())
     }
 
     fn get_webhook_event_type(
         &self,
-        _request: &IncomingWebhookRequestDetails<'_>,
+        request: &IncomingWebhookRequestDetails<'_>,
     ) -> CustomResult<IncomingWebhookEvent, errors::ConnectorError> {
-        Err(errors::ConnectorError::WebhooksNotImplemented.into())
+        let webhook: wave::WaveWebhookEventTypeBody = request
+            .body
+            .parse_struct("WaveWebhookEventTypeBody")
+            .change_context(errors::ConnectorError::WebhookEventTypeNotFound)?;
+        Ok(webhook.event_type.incoming_webhook_event())
     }
 
     fn get_webhook_resource_object(
         &self,
-        _request: &IncomingWebhookRequestDetails<'_>,
+        request: &IncomingWebhookRequestDetails<'_>,
     ) -> CustomResult<Box<dyn masking::ErasedMaskSerialize>, errors::ConnectorError> {
-        Err(errors::ConnectorError::WebhooksNotImplemented.into())
+        let webhook: wave::WaveWebhookBody = request
+            .body
+            .parse_struct("WaveWebhookBody")
+            .change_context(errors::ConnectorError::WebhookResourceObjectNotFound)?;
+        // Refund objects keep their transaction id so the router can reconcile them to the payment
+        Ok(Box::new(webhook.data))
+    }
+}
+
+const WAVE_HTTP_CLIENT_INIT_FAILED: &str = "http client init failed";
+/// Used when `connectors.wave.max_retries` is not configured
+const WAVE_DEFAULT_MAX_RETRIES: u32 = 3;
+/// Used when `connectors.wave.max_response_body_bytes` is not configured
+const WAVE_DEFAULT_MAX_RESPONSE_BODY_BYTES: usize = 1024 * 1024;
+
+/// HTTP client and limits for the raw Wave API calls made outside the connector flows
+#[derive(Debug)]
+struct WaveServiceClient {
+    client: reqwest::Client,
+    max_retries: u32,
+    max_response_body_bytes: usize,
+    temporary_merchant_limiter: WaveCreationLimiter,
+}
+
+impl WaveServiceClient {
+    fn new(
+        config: &hyperswitch_domain_models::connector_endpoints::WaveConnectorConfig,
+    ) -> CustomResult<Self, errors::ConnectorError> {
+        let mut builder = reqwest::Client::builder();
+        if let Some(timeout_secs) = config.request_timeout_secs {
+            builder = builder.timeout(std::time::Duration::from_secs(timeout_secs));
+        }
+        Ok(Self {
+            client: build_wave_http_client(builder)?,
+            max_retries: config.max_retries.unwrap_or(WAVE_DEFAULT_MAX_RETRIES),
+            max_response_body_bytes: config
+                .max_response_body_bytes
+                .unwrap_or(WAVE_DEFAULT_MAX_RESPONSE_BODY_BYTES),
+            temporary_merchant_limiter: WaveCreationLimiter::new(
+                config
+                    .max_concurrent_temporary_merchants
+                    .unwrap_or(WAVE_DEFAULT_MAX_CONCURRENT_TEMPORARY_MERCHANTS),
+            ),
+        })
+    }
+}
+
+static WAVE_SERVICE_CLIENT: OnceLock<WaveServiceClient> = OnceLock::new();
+
+/// Build the HTTP client, reporting init failures separately from request failures
+fn build_wave_http_client(
+    builder: reqwest::ClientBuilder,
+) -> CustomResult<reqwest::Client, errors::ConnectorError> {
+    builder.build().change_context(errors::ConnectorError::ProcessingStepFailed(Some(
+        WAVE_HTTP_CLIENT_INIT_FAILED.into(),
+    )))
+}
+
+/// Build the shared Wave service client from `connectors.wave`. Called once at application
+/// startup, before any service call, so every call uses the configured timeout and limits
+pub fn init_wave_service_client(
+    config: &hyperswitch_domain_models::connector_endpoints::WaveConnectorConfig,
+) -> CustomResult<(), errors::ConnectorError> {
+    WAVE_SERVICE_CLIENT
+        .set(WaveServiceClient::new(config)?)
+        .map_err(|_| {
+            error_stack::report!(errors::ConnectorError::ProcessingStepFailed(Some(
+                WAVE_HTTP_CLIENT_INIT_FAILED.into(),
+            )))
+            .attach_printable("Wave service client is already initialised")
+        })
+}
+
+/// Shared Wave service client, with the default settings when startup did not initialise it
+fn wave_service_client() -> CustomResult<&'static WaveServiceClient, errors::ConnectorError> {
+    if let Some(service_client) = WAVE_SERVICE_CLIENT.get() {
+        return Ok(service_client);
+    }
+    let service_client = WaveServiceClient::new(&Default::default())?;
+    Ok(WAVE_SERVICE_CLIENT.get_or_init(|| service_client))
+}
+
+fn wave_http_client() -> CustomResult<&'static reqwest::Client, errors::ConnectorError> {
+    Ok(&wave_service_client()?.client)
+}
+
+/// Send a raw Wave service request with a fresh correlation id. Timeouts are reported as such;
+/// any other transport failure (connection refused, TLS) means the call itself did not go through
+async fn send_wave_request(
+    request: reqwest::RequestBuilder,
+) -> CustomResult<reqwest::Response, errors::ConnectorError> {
+    request
+        .header(headers::X_REQUEST_ID, generate_wave_request_id())
+        .send()
+        .await
+        .map_err(|error| {
+            let connector_error = if error.is_timeout() {
+                errors::ConnectorError::RequestTimeoutReceived
+            } else {
+                errors::ConnectorError::ProcessingStepFailed(None)
+            };
+            error_stack::Report::new(error).change_context(connector_error)
+        })
+}
+
+/// Read a raw Wave response body, rejecting it once it grows past `max_bytes`
+/// instead of buffering whatever the endpoint sends
+async fn read_wave_response_body(
+    mut response: reqwest::Response,
+    max_bytes: usize,
+) -> CustomResult<Vec<u8>, errors::ConnectorError> {
+    let too_large = || {
+        error_stack::report!(errors::ConnectorError::ResponseDeserializationFailed)
+            .attach_printable(format!("Wave response body exceeds {max_bytes} bytes"))
+    };
+    if response
+        .content_length()
+        .is_some_and(|length| length > max_bytes as u64)
+    {
+        return Err(too_large());
+    }
+    let mut body = Vec::new();
+    while let Some(chunk) = response
+        .chunk()
+        .await
+        .change_context(errors::ConnectorError::ResponseDeserializationFailed)?
+    {
+        if body.len() + chunk.len() > max_bytes {
+            return Err(too_large());
+        }
+        body.extend_from_slice(&chunk);
+    }
+    Ok(body)
+}
+
+/// Read a raw Wave response body as text within the configured size limit
+async fn read_wave_response_text(
+    response: reqwest::Response,
+) -> CustomResult<String, errors::ConnectorError> {
+    let max_bytes = wave_service_client()?.max_response_body_bytes;
+    let body = read_wave_response_body(response, max_bytes).await?;
+    Ok(String::from_utf8_lossy(&body).into_owned())
+}
+
+/// Convert a non-success Wave response into the parsed Wave API error
+async fn wave_api_error<T>(response: reqwest::Response) -> CustomResult<T, errors::ConnectorError> {
+    let status = response.status().as_u16();
+    let error_text = read_wave_response_text(response).await?;
+    Err(wave::parse_wave_api_error(status, &error_text)).change_context(errors::ConnectorError::ProcessingStepFailed(None))
+}
+
+/// Send a raw Wave service request, parsing the JSON body on success and the Wave API error otherwise
+async fn send_and_parse<T: serde::de::DeserializeOwned>(
+    request: reqwest::RequestBuilder,
+) -> CustomResult<T, errors::ConnectorError> {
+    parse_wave_response(send_wave_request(request).await?).await
+}
+
+/// Parse the JSON body of a successful Wave response, or the Wave API error otherwise
+async fn parse_wave_response<T: serde::de::DeserializeOwned>(
+    response: reqwest::Response,
+) -> CustomResult<T, errors::ConnectorError> {
+    if response.status().is_success() {
+        let body = read_wave_response_text(response).await?;
+        serde_json::from_str::<T>(&body)
+            .change_context(errors::ConnectorError::ResponseDeserializationFailed)
+    } else {
+        wave_api_error(response).await
     }
 }
 
@@ -1009,29 +1830,14 @@ impl WaveAggregatedMerchantService {
         let url = format!("{}{}", base_url, WAVE_AGGREGATED_MERCHANT_CREATE);
         let auth_header = format!("Bearer {}", api_key.peek());
         
-        let client = reqwest::Client::new();
-        let response = client
-            .post(&url)
-            .header(headers::AUTHORIZATION, auth_header)
-            .header(headers::CONTENT_TYPE, "application/json")
-            .json(&request)
-            .send()
-            .await
-            .change_context(errors::ConnectorError::RequestEncodingFailed)?;
-            
-        if response.status().is_success() {
-            response
-                .json::<wave::WaveAggregatedMerchant>()
-                .await
-                .change_context(errors::ConnectorError::ResponseDeserializationFailed)
-        } else {
-            let status = response.status().as_u16();
-            let error_text = response
-                .text()
-                .await
-                .change_context(errors::ConnectorError::ResponseDeserializationFailed)?;
-            Err(wave::parse_wave_api_error(status, &error_text)).change_context(errors::ConnectorError::ProcessingStepFailed(None))
-        }
+        send_and_parse::<wave::WaveAggregatedMerchant>(
+            wave_http_client()?
+                .post(&url)
+                .header(headers::AUTHORIZATION, auth_header)
+                .header(headers::CONTENT_TYPE, "application/json")
+                .json(&request),
+        )
+        .await
     }
     
     /// List aggregated merchants with pagination support
@@ -1041,45 +1847,35 @@ impl WaveAggregatedMerchantService {
         limit: Option<u32>,
         cursor: Option<String>,
     ) -> CustomResult<wave::WaveAggregatedMerchantListResponse, errors::ConnectorError> {
-        let mut url = format!("{}{}", base_url, WAVE_AGGREGATED_MERCHANT_LIST);
-        
-        // Add query parameters for pagination
-        let mut query_params = Vec::new();
-        if let Some(limit_val) = limit {
-            query_params.push(format!("limit={}", limit_val));
-        }
-        if let Some(cursor_val) = cursor {
-            query_params.push(format!("cursor={}", cursor_val));
-        }
-        
-        if !query_params.is_empty() {
-            url.push('?');
-            url.push_str(&query_params.join("&"));
+        let limit = limit.unwrap_or(WAVE_AGGREGATED_MERCHANT_LIST_DEFAULT_LIMIT);
+        if !(1..=WAVE_AGGREGATED_MERCHANT_LIST_MAX_LIMIT).contains(&limit) {
+            return Err(error_stack::report!(errors::ConnectorError::InvalidDataFormat {
+                field_name: "limit",
+            })
+            .attach_printable(format!(
+                "Aggregated merchant list limit {limit} must be between 1 and {WAVE_AGGREGATED_MERCHANT_LIST_MAX_LIMIT}"
+            )));
         }
+
+        // The cursor comes from the caller, so it is percent-encoded like any other id
+        let limit = limit.to_string();
+        let page = match cursor.as_deref().filter(|cursor| !cursor.is_empty()) {
+            Some(cursor) => build_wave_url(
+                WAVE_AGGREGATED_MERCHANT_LIST_PAGE_AFTER,
+                &[("limit", &limit), ("cursor", cursor)],
+            )?,
+            None => build_wave_url(WAVE_AGGREGATED_MERCHANT_LIST_PAGE, &[("limit", &limit)])?,
+        };
+        let url = format!("{}{}", base_url, page);
         
         let auth_header = format!("Bearer {}", api_key.peek());
         
-        let client = reqwest::Client::new();
-        let response = client
-            .get(&url)
-            .header(headers::AUTHORIZATION, auth_header)
-            .send()
-            .await
-            .change_context(errors::ConnectorError::RequestEncodingFailed)?;
-            
-        if response.status().is_success() {
-            response
-                .json::<wave::WaveAggregatedMerchantListResponse>()
-                .await
-                .change_context(errors::ConnectorError::ResponseDeserializationFailed)
-        } else {
-            let status = response.status().as_u16();
-            let error_text = response
-                .text()
-                .await
-                .change_context(errors::ConnectorError::ResponseDeserializationFailed)?;
-            Err(wave::parse_wave_api_error(status, &error_text)).change_context(errors::ConnectorError::ProcessingStepFailed(None))
-        }
+        send_and_parse::<wave::WaveAggregatedMerchantListResponse>(
+            wave_http_client()?
+                .get(&url)
+                .header(headers::AUTHORIZATION, auth_header),
+        )
+        .await
     }
     
     /// Get aggregated merchant by ID with enhanced error handling
@@ -1088,37 +1884,17 @@ impl WaveAggregatedMerchantService {
         base_url: &str,
         merchant_id: &str,
     ) -> CustomResult<wave::WaveAggregatedMerchant, errors::ConnectorError> {
-        // Validate merchant ID format
-        if merchant_id.is_empty() || !merchant_id.starts_with("am-") {
-            return Err(errors::ConnectorError::InvalidConnectorConfig {
-                config: "Invalid aggregated merchant ID format"
-            }.into());
-        }
+        let aggregated_merchant_id = wave::WaveAggregatedMerchantId::new(merchant_id)?;
         
-        let url = format!("{}{}", base_url, WAVE_AGGREGATED_MERCHANT_BY_ID.replace("{id}", merchant_id));
+        let url = format!("{}{}", base_url, build_wave_url(WAVE_AGGREGATED_MERCHANT_BY_ID, &[("id", aggregated_merchant_id.as_ref())])?);
         let auth_header = format!("Bearer {}", api_key.peek());
         
-        let client = reqwest::Client::new();
-        let response = client
-            .get(&url)
-            .header(headers::AUTHORIZATION, auth_header)
-            .send()
-            .await
-            .change_context(errors::ConnectorError::RequestEncodingFailed)?;
-            
-        if response.status().is_success() {
-            response
-                .json::<wave::WaveAggregatedMerchant>()
-                .await
-                .change_context(errors::ConnectorError::ResponseDeserializationFailed)
-        } else {
-            let status = response.status().as_u16();
-            let error_text = response
-                .text()
-                .await
-                .change_context(errors::ConnectorError::ResponseDeserializationFailed)?;
-            Err(wave::parse_wave_api_error(status, &error_text)).change_context(errors::ConnectorError::ProcessingStepFailed(None))
-        }
+        send_and_parse::<wave::WaveAggregatedMerchant>(
+            wave_http_client()?
+                .get(&url)
+                .header(headers::AUTHORIZATION, auth_header),
+        )
+        .await
     }
     
     /// Update aggregated merchant with validation
@@ -1127,17 +1903,13 @@ impl WaveAggregatedMerchantService {
         base_url: &str,
         merchant_id: &str,
         request: wave::WaveAggregatedMerchantUpdateRequest,
+        verify: bool,
     ) -> CustomResult<wave::WaveAggregatedMerchant, errors::ConnectorError> {
-        // Validate merchant ID format
-        if merchant_id.is_empty() || !merchant_id.starts_with("am-") {
-            return Err(errors::ConnectorError::InvalidConnectorConfig {
-                config: "Invalid aggregated merchant ID format"
-            }.into());
-        }
+        let aggregated_merchant_id = wave::WaveAggregatedMerchantId::new(merchant_id)?;
         
         // Validate update request fields if provided
         if let Some(ref name) = request.name {
-            if name.is_empty() || name.len() > 255 {
+            if name.is_empty() || name.chars().count() > 255 {
                 return Err(errors::ConnectorError::InvalidConnectorConfig {
                     config: "Merchant name must be between 1 and 255 characters"
                 }.into());
@@ -1145,74 +1917,101 @@ impl WaveAggregatedMerchantService {
         }
         
         if let Some(ref description) = request.business_description {
-            if description.is_empty() || description.len() > 500 {
+            if description.is_empty() || description.chars().count() > 500 {
                 return Err(errors::ConnectorError::InvalidConnectorConfig {
                     config: "Business description must be between 1 and 500 characters"
                 }.into());
             }
         }
         
-        let url = format!("{}{}", base_url, WAVE_AGGREGATED_MERCHANT_UPDATE.replace("{id}", merchant_id));
+        let url = format!("{}{}", base_url, build_wave_url(WAVE_AGGREGATED_MERCHANT_UPDATE, &[("id", aggregated_merchant_id.as_ref())])?);
         let auth_header = format!("Bearer {}", api_key.peek());
         
-        let client = reqwest::Client::new();
-        let response = client
-            .put(&url)
-            .header(headers::AUTHORIZATION, auth_header)
-            .header(headers::CONTENT_TYPE, "application/json")
-            .json(&request)
-            .send()
-            .await
-            .change_context(errors::ConnectorError::RequestEncodingFailed)?;
-            
-        if response.status().is_success() {
-            response
-                .json::<wave::WaveAggregatedMerchant>()
-                .await
-                .change_context(errors::ConnectorError::ResponseDeserializationFailed)
-        } else {
-            let status = response.status().as_u16();
-            let error_text = response
-                .text()
-                .await
-                .change_context(errors::ConnectorError::ResponseDeserializationFailed)?;
-            Err(wave::parse_wave_api_error(status, &error_text)).change_context(errors::ConnectorError::ProcessingStepFailed(None))
+        let updated = send_and_parse::<wave::WaveAggregatedMerchant>(
+            wave_http_client()?
+                .put(&url)
+                .header(headers::AUTHORIZATION, auth_header)
+                .header(headers::CONTENT_TYPE, "application/json")
+                .json(&request.to_wave_body()?),
+        )
+        .await?;
+
+        if !verify {
+            return Ok(updated);
+        }
+
+        // Re-fetch so an update the server accepted but did not apply is not reported as success
+        let current = Self::get_aggregated_merchant(api_key, base_url, merchant_id).await?;
+        let unapplied_fields = wave::find_unapplied_update_fields(&request, &current);
+        if !unapplied_fields.is_empty() {
+            return Err(errors::ConnectorError::from(
+                wave::WaveAggregatedMerchantError::UpdateNotApplied {
+                    merchant_id: merchant_id.to_string(),
+                    fields: unapplied_fields,
+                },
+            )
+            .into());
         }
+        Ok(current)
     }
     
     /// Delete aggregated merchant with proper validation
+    /// Deleting a merchant that is already gone succeeds; one Wave refuses to delete yields `DeleteConflict`.
+    /// With `confirm_inactive`, an active merchant is refused before the DELETE is sent.
     pub async fn delete_aggregated_merchant(
         api_key: &Secret<String>,
         base_url: &str,
         merchant_id: &str,
+        confirm_inactive: bool,
     ) -> CustomResult<(), errors::ConnectorError> {
-        // Validate merchant ID format
-        if merchant_id.is_empty() || !merchant_id.starts_with("am-") {
-            return Err(errors::ConnectorError::InvalidConnectorConfig {
-                config: "Invalid aggregated merchant ID format"
-            }.into());
+        let aggregated_merchant_id = wave::WaveAggregatedMerchantId::new(merchant_id)?;
+
+        if confirm_inactive {
+            match Self::find_aggregated_merchant(api_key, base_url, merchant_id).await? {
+                Some(merchant) if merchant.status == wave::WaveAggregatedMerchantStatus::Active => {
+                    return Err(errors::ConnectorError::from(
+                        wave::WaveAggregatedMerchantError::DeleteConflict {
+                            merchant_id: merchant_id.to_string(),
+                            reason: "merchant is still active".to_string(),
+                        },
+                    )
+                    .into());
+                }
+                Some(_) => {}
+                // Same not-found rule as `merchant_exists`, so there is nothing left to delete
+                None => return Ok(()),
+            }
         }
         
-        let url = format!("{}{}", base_url, WAVE_AGGREGATED_MERCHANT_DELETE.replace("{id}", merchant_id));
+        let url = format!("{}{}", base_url, build_wave_url(WAVE_AGGREGATED_MERCHANT_DELETE, &[("id", aggregated_merchant_id.as_ref())])?);
         let auth_header = format!("Bearer {}", api_key.peek());
         
-        let client = reqwest::Client::new();
-        let response = client
-            .delete(&url)
-            .header(headers::AUTHORIZATION, auth_header)
-            .send()
-            .await
-            .change_context(errors::ConnectorError::RequestEncodingFailed)?;
+        let response = send_wave_request(
+            wave_http_client()?
+                .delete(&url)
+                .header(headers::AUTHORIZATION, auth_header),
+        )
+        .await?;
             
-        if response.status().is_success() {
-            Ok(())
-        } else {
-            let status = response.status().as_u16();
-            let error_text = response
-                .text()
-                .await
-                .change_context(errors::ConnectorError::ResponseDeserializationFailed)?;
-            Err(wave::parse_wave_api_error(status, &error_text)).change_context(errors::ConnectorError::ProcessingStepFailed(None))
+        let status = response.status().as_u16();
+        match status {
+            200..=299 => Ok(()),
+            // Already gone, so the delete is idempotent
+            404 => Ok(()),
+            409 => {
+                let error_text = read_wave_response_text(response).await?;
+                let reason = wave::WaveErrorResponse::parse(error_text.as_bytes())
+                    .map(|error_response| error_response.message)
+                    .unwrap_or(error_text);
+                Err(errors::ConnectorError::from(
+                    wave::WaveAggregatedMerchantError::DeleteConflict {
+                        merchant_id: merchant_id.to_string(),
+                        reason,
+                    },
+                )
+                .into())
+            }
+            _ => wave_api_error(response).await,
         }
     }
     
@@ -1222,20 +2021,44 @@ impl WaveAggregatedMerchantService {
         base_url: &str,
         merchant_id: &str,
     ) -> CustomResult<bool, errors::ConnectorError> {
-        match Self::get_aggregated_merchant(api_key, base_url, merchant_id).await {
-            Ok(_) => Ok(true),
-            Err(err) => {
-                // Check if the error is specifically "not found"
-                if let Some(error_stack) = err.downcast_ref::<errors::ConnectorError>() {
-                    match error_stack {
-                        errors::ConnectorError::ProcessingStepFailed(_) => Ok(false),
-                        _ => Err(err),
-                    }
-                } else {
-                    Err(err)
-                }
-            }
+        Ok(Self::find_aggregated_merchant(api_key, base_url, merchant_id)
+            .await?
+            .is_some())
+    }
+
+    /// Aggregated merchant by ID, `None` only when Wave answers 404 or `AGGREGATED_MERCHANT_NOT_FOUND`.
+    /// Auth failures, rate limiting and outages stay errors so they are never mistaken for a missing merchant
+    async fn find_aggregated_merchant(
+        api_key: &Secret<String>,
+        base_url: &str,
+        merchant_id: &str,
+    ) -> CustomResult<Option<wave::WaveAggregatedMerchant>, errors::ConnectorError> {
+        let aggregated_merchant_id = wave::WaveAggregatedMerchantId::new(merchant_id)?;
+
+        let url = format!("{}{}", base_url, build_wave_url(WAVE_AGGREGATED_MERCHANT_BY_ID, &[("id", aggregated_merchant_id.as_ref())])?);
+        let auth_header = format!("Bearer {}", api_key.peek());
+
+        let response = send_wave_request(
+            wave_http_client()?
+                .get(&url)
+                .header(headers::AUTHORIZATION, auth_header),
+        )
+        .await?;
+
+        if response.status().is_success() {
+            return parse_wave_response(response).await.map(Some);
         }
+        let status = response.status().as_u16();
+        let error_text = read_wave_response_text(response).await?;
+        let not_found = status == 404
+            || wave::WaveErrorResponse::parse(error_text.as_bytes()).is_ok_and(|error_response| {
+                error_response.error_code() == wave::WaveErrorCode::AggregatedMerchantNotFound
+            });
+        if not_found {
+            return Ok(None);
+        }
+        Err(wave::parse_wave_api_error(status, &error_text))
+            .change_context(errors::ConnectorError::ProcessingStepFailed(None))
     }
     
     /// Batch get aggregated merchants by IDs (utility method)
@@ -1254,3 +2077,2771 @@ impl WaveAggregatedMerchantService {
         Ok(results)
     }
 }
+
+/// Backoff bounds for aggregated merchant validation retries
+const WAVE_RESOLVER_RETRY_BASE_DELAY: std::time::Duration = std::time::Duration::from_millis(100);
+const WAVE_RESOLVER_RETRY_MAX_DELAY: std::time::Duration = std::time::Duration::from_secs(2);
+
+/// Full-jitter exponential backoff: a uniform delay between zero and the capped exponential delay
+/// for this attempt (1-based). The RNG is passed in so tests can seed it.
+fn wave_backoff_with_jitter<R: rand::Rng + ?Sized>(
+    base: std::time::Duration,
+    cap: std::time::Duration,
+    attempt: u32,
+    rng: &mut R,
+) -> std::time::Duration {
+    let exponential = base
+        .checked_mul(2_u32.saturating_pow(attempt.saturating_sub(1)))
+        .unwrap_or(cap);
+    let ceiling = std::cmp::min(exponential, cap);
+    let ceiling_ms = u64::try_from(ceiling.as_millis()).unwrap_or(u64::MAX);
+    std::time::Duration::from_millis(rng.gen_range(0..=ceiling_ms))
+}
+
+// Wave Checkout Service
+pub struct WaveCheckoutService;
+
+impl WaveCheckoutService {
+    /// Fetch a checkout session directly, outside of the PSync flow (e.g. for reconciliation jobs)
+    pub async fn get_checkout_session(
+        api_key: &Secret<String>,
+        base_url: &str,
+        session_id: &str,
+    ) -> CustomResult<wave::WavePaymentStatusResponse, errors::ConnectorError> {
+        let session_id = wave::WaveSessionId::new(session_id.trim())?;
+        let url = format!("{}{}", base_url, build_wave_url(WAVE_CHECKOUT_SESSION_STATUS, &[("session_id", session_id.as_ref())])?);
+        let auth_header = format!("Bearer {}", api_key.peek());
+        
+        send_and_parse::<wave::WavePaymentStatusResponse>(
+            wave_http_client()?
+                .get(&url)
+                .header(headers::AUTHORIZATION, auth_header),
+        )
+        .await
+    }
+}
+
+// Wave Webhook Service
+pub struct WaveWebhookService;
+
+impl WaveWebhookService {
+    /// Subscribe a callback URL to the given events, the returned id belongs in the connector
+    /// metadata (see `wave::with_webhook_id`) so the endpoint can be cleaned up later
+    pub async fn register_webhook(
+        api_key: &Secret<String>,
+        base_url: &str,
+        callback_url: &str,
+        events: Vec<wave::WaveWebhookEvent>,
+    ) -> CustomResult<wave::WaveWebhookEndpoint, errors::ConnectorError> {
+        url::Url::parse(callback_url).change_context(
+            errors::ConnectorError::RequestEncodingFailedWithReason(
+                "Webhook callback URL is not a valid URL".to_string(),
+            ),
+        )?;
+        if events.is_empty() || events.contains(&wave::WaveWebhookEvent::Unknown) {
+            return Err(errors::ConnectorError::RequestEncodingFailedWithReason(
+                "Webhook registration needs at least one known event".to_string(),
+            )
+            .into());
+        }
+
+        let request = wave::WaveWebhookRegistrationRequest {
+            url: callback_url.to_string(),
+            events,
+        };
+        let url = format!("{}{}", base_url, WAVE_WEBHOOKS);
+        let auth_header = format!("Bearer {}", api_key.peek());
+
+        send_and_parse::<wave::WaveWebhookEndpoint>(
+            wave_http_client()?
+                .post(&url)
+                .header(headers::AUTHORIZATION, auth_header)
+                .header(headers::CONTENT_TYPE, "application/json")
+                .json(&request),
+        )
+        .await
+    }
+
+    /// List the webhook endpoints registered on the Wave account
+    pub async fn list_webhooks(
+        api_key: &Secret<String>,
+        base_url: &str,
+    ) -> CustomResult<Vec<wave::WaveWebhookEndpoint>, errors::ConnectorError> {
+        let url = format!("{}{}", base_url, WAVE_WEBHOOKS);
+        let auth_header = format!("Bearer {}", api_key.peek());
+
+        send_and_parse::<wave::WaveWebhookEndpointListResponse>(
+            wave_http_client()?
+                .get(&url)
+                .header(headers::AUTHORIZATION, auth_header),
+        )
+        .await
+        .map(|list| list.webhooks)
+    }
+
+    /// Remove a webhook endpoint, treating an already deleted one as success
+    pub async fn delete_webhook(
+        api_key: &Secret<String>,
+        base_url: &str,
+        webhook_id: &str,
+    ) -> CustomResult<(), errors::ConnectorError> {
+        let url = format!("{}{}", base_url, build_wave_url(WAVE_WEBHOOK_BY_ID, &[("webhook_id", webhook_id.trim())])?);
+        let auth_header = format!("Bearer {}", api_key.peek());
+
+        let response = send_wave_request(
+            wave_http_client()?
+                .delete(&url)
+                .header(headers::AUTHORIZATION, auth_header),
+        )
+        .await?;
+
+        match response.status().as_u16() {
+            200..=299 | 404 => Ok(()),
+            _ => wave_api_error(response).await,
+        }
+    }
+}
+
+/// Upper bound on refund status requests in flight for a single batch
+const WAVE_REFUND_STATUS_CONCURRENCY: usize = 5;
+
+// Wave Refund Service
+pub struct WaveRefundService;
+
+impl WaveRefundService {
+    /// Fetch a single refund directly, outside of the RSync flow
+    pub async fn get_refund_status(
+        api_key: &Secret<String>,
+        base_url: &str,
+        refund_id: &str,
+    ) -> CustomResult<wave::WaveRefundResponse, errors::ConnectorError> {
+        let refund_id = wave::WaveRefundId::new(refund_id.trim())?;
+        let url = format!("{}{}", base_url, build_wave_url(WAVE_REFUND_STATUS, &[("refund_id", refund_id.as_ref())])?);
+        let auth_header = format!("Bearer {}", api_key.peek());
+
+        send_and_parse::<wave::WaveRefundResponse>(
+            wave_http_client()?
+                .get(&url)
+                .header(headers::AUTHORIZATION, auth_header),
+        )
+        .await
+    }
+
+    /// Fetch several refunds concurrently for reconciliation jobs, one result per id in input order
+    pub async fn get_multiple_refund_statuses(
+        api_key: &Secret<String>,
+        base_url: &str,
+        refund_ids: &[String],
+    ) -> Vec<(String, CustomResult<wave::WaveRefundResponse, errors::ConnectorError>)> {
+        futures::stream::iter(refund_ids)
+            .map(|refund_id| async move {
+                let result = Self::get_refund_status(api_key, base_url, refund_id).await;
+                (refund_id.clone(), result)
+            })
+            .buffered(WAVE_REFUND_STATUS_CONCURRENCY)
+            .collect()
+            .await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::marker::PhantomData;
+
+    use common_enums::{AttemptStatus, AuthenticationType, Currency, PaymentMethod};
+    use common_utils::{id_type, types::MinorUnit};
+    use hyperswitch_domain_models::{
+        payment_address::PaymentAddress,
+        payment_method_data::{MobilePayRedirection, PaymentMethodData, WalletData},
+        router_data::{ConnectorAuthType, RouterData},
+    };
+    use wiremock::{
+        matchers::{body_partial_json, header, header_exists, method, path, query_param},
+        Mock, MockServer, ResponseTemplate,
+    };
+
+    use super::*;
+
+
+    pub(super) fn build_router_data<F, Req, Res>(request: Req) -> RouterData<F, Req, Res> {
+        RouterData {
+            flow: PhantomData,
+            merchant_id: id_type::MerchantId::default(),
+            customer_id: None,
+            connector_customer: None,
+            connector: "wave".to_string(),
+            payment_id: "pay_wave_test".to_string(),
+            attempt_id: "pay_wave_test_1".to_string(),
+            tenant_id: id_type::TenantId::try_from_string("public".to_string()).unwrap(),
+            status: AttemptStatus::Started,
+            payment_method: PaymentMethod::Wallet,
+            connector_auth_type: ConnectorAuthType::HeaderKey {
+                api_key: Secret::new("wave_test_key".to_string()),
+            },
+            description: None,
+            address: PaymentAddress::default(),
+            auth_type: AuthenticationType::NoThreeDs,
+            connector_meta_data: None,
+            connector_wallets_details: None,
+            amount_captured: None,
+            access_token: None,
+            session_token: None,
+            reference_id: None,
+            payment_method_token: None,
+            recurring_mandate_payment_data: None,
+            preprocessing_id: None,
+            payment_method_balance: None,
+            connector_api_version: None,
+            request,
+            response: Err(ErrorResponse::default()),
+            connector_request_reference_id: "pay_wave_test_1".to_string(),
+            #[cfg(feature = "payouts")]
+            payout_method_data: None,
+            #[cfg(feature = "payouts")]
+            quote_id: None,
+            test_mode: None,
+            connector_http_status_code: None,
+            external_latency: None,
+            apple_pay_flow: None,
+            frm_metadata: None,
+            dispute_id: None,
+            refund_id: None,
+            connector_response: None,
+            payment_method_status: None,
+            minor_amount_captured: None,
+            minor_amount_capturable: None,
+            integrity_check: Ok(()),
+            additional_merchant_data: None,
+            header_payload: None,
+            connector_mandate_request_reference_id: None,
+            l2_l3_data: None,
+            authentication_id: None,
+            psd2_sca_exemption_type: None,
+            raw_connector_response: None,
+            is_payment_id_from_merchant: None,
+        }
+    }
+
+    fn mobile_pay_redirect() -> PaymentMethodData {
+        PaymentMethodData::Wallet(WalletData::MobilePayRedirect(Box::new(
+            MobilePayRedirection {},
+        )))
+    }
+
+    pub(super) fn refunds_request_data(refund_amount: i64) -> RefundsData {
+        RefundsData {
+            refund_id: "ref_wave_test".to_string(),
+            connector_transaction_id: "cos-test123".to_string(),
+            connector_refund_id: None,
+            currency: Currency::XOF,
+            payment_amount: 1000,
+            reason: None,
+            webhook_url: None,
+            refund_amount,
+            connector_metadata: None,
+            refund_connector_metadata: None,
+            browser_info: None,
+            split_refunds: None,
+            minor_payment_amount: MinorUnit::new(1000),
+            minor_refund_amount: MinorUnit::new(refund_amount),
+            integrity_object: None,
+            refund_status: common_enums::RefundStatus::Pending,
+            merchant_account_id: None,
+            merchant_config_currency: None,
+            capture_method: None,
+            additional_payment_method_data: None,
+        }
+    }
+
+    pub(super) fn authorize_request_data(amount: i64) -> PaymentsAuthorizeData {
+        PaymentsAuthorizeData {
+            payment_method_data: mobile_pay_redirect(),
+            amount,
+            order_tax_amount: None,
+            email: None,
+            customer_name: None,
+            currency: Currency::XOF,
+            confirm: true,
+            statement_descriptor_suffix: None,
+            statement_descriptor: None,
+            capture_method: None,
+            router_return_url: Some("https://hyperswitch.example/payments/return".to_string()),
+            webhook_url: None,
+            complete_authorize_url: None,
+            setup_future_usage: None,
+            mandate_id: None,
+            off_session: None,
+            customer_acceptance: None,
+            setup_mandate_details: None,
+            browser_info: None,
+            order_details: None,
+            order_category: None,
+            session_token: None,
+            enrolled_for_3ds: false,
+            related_transaction_id: None,
+            payment_experience: None,
+            payment_method_type: None,
+            surcharge_details: None,
+            customer_id: None,
+            request_incremental_authorization: false,
+            metadata: None,
+            authentication_data: None,
+            request_extended_authorization: None,
+            split_payments: None,
+            minor_amount: MinorUnit::new(amount),
+            merchant_order_reference_id: None,
+            integrity_object: None,
+            shipping_cost: None,
+            additional_payment_method_data: None,
+            merchant_account_id: None,
+            merchant_config_currency: None,
+            connector_testing_data: None,
+            order_id: None,
+            locale: None,
+            payment_channel: None,
+            enable_partial_authorization: None,
+        }
+    }
+
+    /// Checkout session request the Authorize flow would send for `router_data`
+    pub(super) fn checkout_request_for(
+        router_data: &PaymentsAuthorizeRouterData,
+    ) -> CustomResult<wave::WaveCheckoutSessionRequest, errors::ConnectorError> {
+        let item = wave::WaveRouterData::try_from((
+            &api::CurrencyUnit::Minor,
+            router_data.request.currency,
+            router_data.request.minor_amount,
+            router_data,
+        ))?;
+        wave::WaveCheckoutSessionRequest::try_from(&item)
+    }
+
+    async fn start_mock_server() -> (MockServer, String) {
+        let mock_server = MockServer::start().await;
+        // Wave endpoint templates are relative, so the base URL carries the trailing slash
+        let base_url = format!("{}/", mock_server.uri());
+        (mock_server, base_url)
+    }
+
+    fn test_api_key() -> Secret<String> {
+        Secret::new("wave_test_key".to_string())
+    }
+
+    #[test]
+    fn test_setup_mandate_is_explicitly_not_supported() {
+        let req: SetupMandateRouterData = build_router_data(SetupMandateRequestData {
+            currency: Currency::XOF,
+            payment_method_data: mobile_pay_redirect(),
+            amount: Some(1000),
+            confirm: true,
+            statement_descriptor_suffix: None,
+            customer_acceptance: None,
+            mandate_id: None,
+            setup_future_usage: None,
+            off_session: None,
+            setup_mandate_details: None,
+            router_return_url: None,
+            webhook_url: None,
+            browser_info: None,
+            email: None,
+            customer_name: None,
+            return_url: None,
+            payment_method_type: None,
+            request_incremental_authorization: false,
+            metadata: None,
+            complete_authorize_url: None,
+            capture_method: None,
+            enrolled_for_3ds: false,
+            related_transaction_id: None,
+            minor_amount: Some(MinorUnit::new(1000)),
+            shipping_cost: None,
+            connector_testing_data: None,
+            customer_id: None,
+            enable_partial_authorization: None,
+            payment_channel: None,
+        });
+
+        let result = Wave::new().build_request(&req, &Connectors::default());
+        let error = result.unwrap_err();
+
+        assert_eq!(
+            error.current_context(),
+            &errors::ConnectorError::NotSupported {
+                message: "Setup Mandate flow".to_string(),
+                connector: "wave",
+            }
+        );
+    }
+
+    #[tokio::test]
+    async fn test_get_checkout_session_success() {
+        let (mock_server, base_url) = start_mock_server().await;
+        Mock::given(method("GET"))
+            .and(path("/checkout/sessions/cos-test123"))
+            .and(header("Authorization", "Bearer wave_test_key"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+                "id": "cos-test123",
+                "status": "completed",
+                "amount": "1000",
+                "currency": "XOF",
+                "reference": "pay_wave_test_1",
+                "launch_url": null
+            })))
+            .mount(&mock_server)
+            .await;
+
+        let session =
+            WaveCheckoutService::get_checkout_session(&test_api_key(), &base_url, "cos-test123")
+                .await
+                .unwrap();
+
+        assert_eq!(session.id, "cos-test123");
+        assert_eq!(session.status, wave::WavePaymentStatus::Completed);
+        assert_eq!(session.reference, Some("pay_wave_test_1".to_string()));
+    }
+
+    #[tokio::test]
+    async fn test_get_checkout_session_not_found() {
+        let (mock_server, base_url) = start_mock_server().await;
+        Mock::given(method("GET"))
+            .and(path("/checkout/sessions/cos-missing"))
+            .respond_with(ResponseTemplate::new(404).set_body_json(serde_json::json!({
+                "code": "CHECKOUT_SESSION_NOT_FOUND",
+                "message": "Checkout session not found"
+            })))
+            .mount(&mock_server)
+            .await;
+
+        let error =
+            WaveCheckoutService::get_checkout_session(&test_api_key(), &base_url, "cos-missing")
+                .await
+                .unwrap_err();
+
+        assert!(matches!(
+            error.current_context(),
+            errors::ConnectorError::ProcessingStepFailed(_)
+        ));
+    }
+
+    #[tokio::test]
+    async fn test_get_checkout_session_rejects_empty_id() {
+        let error = WaveCheckoutService::get_checkout_session(&test_api_key(), "https://api.wave.com/", "  ")
+            .await
+            .unwrap_err();
+
+        assert_eq!(
+            error.current_context(),
+            &errors::ConnectorError::MissingConnectorTransactionID
+        );
+    }
+
+    fn aggregated_merchant_json(name: &str) -> serde_json::Value {
+        serde_json::json!({
+            "id": "am-test123",
+            "name": name,
+            "business_type": "ecommerce",
+            "business_description": "Payment processing for Boutique Dakar",
+            "status": "active",
+            "created_at": "2025-01-15T10:00:00Z",
+            "updated_at": "2025-01-15T10:00:00Z"
+        })
+    }
+
+    #[tokio::test]
+    async fn test_aggregated_merchant_crud_round_trip() {
+        let (mock_server, base_url) = start_mock_server().await;
+        let api_key = test_api_key();
+
+        // Create
+        Mock::given(method("POST"))
+            .and(path("/v1/aggregated_merchants"))
+            .and(body_partial_json(serde_json::json!({ "name": "Boutique Dakar" })))
+            .respond_with(
+                ResponseTemplate::new(201).set_body_json(aggregated_merchant_json("Boutique Dakar")),
+            )
+            .expect(1)
+            .mount(&mock_server)
+            .await;
+
+        let created = WaveAggregatedMerchantService::create_aggregated_merchant(
+            &api_key,
+            &base_url,
+            wave::WaveAggregatedMerchantRequest {
+                name: "Boutique Dakar".to_string(),
+                business_type: wave::WaveBusinessType::Ecommerce,
+                business_registration_identifier: None,
+                business_sector: None,
+                website_url: None,
+                business_description: "Payment processing for Boutique Dakar".to_string(),
+                manager_name: None,
+            },
+        )
+        .await
+        .unwrap();
+        assert_eq!(created.id, "am-test123");
+
+        // Get
+        Mock::given(method("GET"))
+            .and(path("/v1/aggregated_merchants/am-test123"))
+            .respond_with(
+                ResponseTemplate::new(200).set_body_json(aggregated_merchant_json("Boutique Dakar")),
+            )
+            .up_to_n_times(1)
+            .mount(&mock_server)
+            .await;
+
+        let fetched =
+            WaveAggregatedMerchantService::get_aggregated_merchant(&api_key, &base_url, &created.id)
+                .await
+                .unwrap();
+        assert_eq!(fetched.name, "Boutique Dakar");
+
+        // Update, then verify the change is visible on the next fetch
+        Mock::given(method("PUT"))
+            .and(path("/v1/aggregated_merchants/am-test123"))
+            .and(body_partial_json(serde_json::json!({ "name": "Boutique Thies" })))
+            .respond_with(
+                ResponseTemplate::new(200).set_body_json(aggregated_merchant_json("Boutique Thies")),
+            )
+            .expect(1)
+            .mount(&mock_server)
+            .await;
+        Mock::given(method("GET"))
+            .and(path("/v1/aggregated_merchants/am-test123"))
+            .respond_with(
+                ResponseTemplate::new(200).set_body_json(aggregated_merchant_json("Boutique Thies")),
+            )
+            .up_to_n_times(1)
+            .mount(&mock_server)
+            .await;
+
+        let updated = WaveAggregatedMerchantService::update_aggregated_merchant(
+            &api_key,
+            &base_url,
+            &created.id,
+            wave::WaveAggregatedMerchantUpdateRequest {
+                name: Some("Boutique Thies".to_string()),
+                business_type: None,
+                business_registration_identifier: None,
+                business_sector: None,
+                website_url: None,
+                business_description: None,
+                manager_name: None,
+                clear_fields: Vec::new(),
+            },
+            false,
+        )
+        .await
+        .unwrap();
+        assert_eq!(updated.id, created.id);
+        assert_eq!(updated.name, "Boutique Thies");
+
+        let refetched =
+            WaveAggregatedMerchantService::get_aggregated_merchant(&api_key, &base_url, &created.id)
+                .await
+                .unwrap();
+        assert_eq!(refetched.name, "Boutique Thies");
+
+        // Delete, after which the merchant is gone
+        Mock::given(method("DELETE"))
+            .and(path("/v1/aggregated_merchants/am-test123"))
+            .respond_with(ResponseTemplate::new(204))
+            .expect(1)
+            .mount(&mock_server)
+            .await;
+        Mock::given(method("GET"))
+            .and(path("/v1/aggregated_merchants/am-test123"))
+            .respond_with(ResponseTemplate::new(404).set_body_json(serde_json::json!({
+                "code": "AGGREGATED_MERCHANT_NOT_FOUND",
+                "message": "Aggregated merchant not found"
+            })))
+            .mount(&mock_server)
+            .await;
+
+        WaveAggregatedMerchantService::delete_aggregated_merchant(&api_key, &base_url, &created.id, false)
+            .await
+            .unwrap();
+
+        let error =
+            WaveAggregatedMerchantService::get_aggregated_merchant(&api_key, &base_url, &created.id)
+                .await
+                .unwrap_err();
+        assert!(matches!(
+            error.current_context(),
+            errors::ConnectorError::ProcessingStepFailed(_)
+        ));
+    }
+
+    #[tokio::test]
+    async fn test_validate_credentials_success() {
+        let (mock_server, base_url) = start_mock_server().await;
+        Mock::given(method("GET"))
+            .and(path("/v1/aggregated_merchants"))
+            .and(query_param("limit", "1"))
+            .and(header("Authorization", "Bearer wave_test_key"))
+            .and(header_exists("X-Request-Id"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+                "aggregated_merchants": [],
+                "total_count": 0,
+                "next_cursor": null
+            })))
+            .expect(1)
+            .mount(&mock_server)
+            .await;
+
+        let auth = wave::WaveAuthType::try_from(&ConnectorAuthType::HeaderKey {
+            api_key: test_api_key(),
+        })
+        .unwrap();
+
+        assert!(Wave::validate_credentials(&auth, &base_url).await.is_ok());
+    }
+
+    #[tokio::test]
+    async fn test_validate_credentials_auth_failure() {
+        let (mock_server, base_url) = start_mock_server().await;
+        Mock::given(method("GET"))
+            .and(path("/v1/aggregated_merchants"))
+            .respond_with(ResponseTemplate::new(401).set_body_string("Unauthorized"))
+            .mount(&mock_server)
+            .await;
+
+        let auth = wave::WaveAuthType::try_from(&ConnectorAuthType::HeaderKey {
+            api_key: Secret::new("revoked_key".to_string()),
+        })
+        .unwrap();
+
+        let error = Wave::validate_credentials(&auth, &base_url)
+            .await
+            .unwrap_err();
+        assert_eq!(
+            error.current_context(),
+            &errors::ConnectorError::AuthenticationFailed
+        );
+    }
+
+    fn webhook_request_details<'a>(
+        headers: &'a actix_web::http::header::HeaderMap,
+        body: &'a [u8],
+    ) -> IncomingWebhookRequestDetails<'a> {
+        IncomingWebhookRequestDetails {
+            method: http::Method::POST,
+            uri: http::Uri::from_static("/webhooks/wave"),
+            headers,
+            body,
+            query_params: String::new(),
+        }
+    }
+
+    #[test]
+    fn test_unknown_webhook_event_type_is_not_supported() {
+        let headers = actix_web::http::header::HeaderMap::new();
+        let body = serde_json::json!({
+            "id": "AE_test123",
+            "type": "merchant.loyalty_points_awarded",
+            "data": {}
+        })
+        .to_string();
+
+        let event = Wave::new()
+            .get_webhook_event_type(&webhook_request_details(&headers, body.as_bytes()))
+            .unwrap();
+
+        assert_eq!(event, IncomingWebhookEvent::EventNotSupported);
+    }
+
+    #[test]
+    fn test_known_webhook_event_type_is_mapped() {
+        let headers = actix_web::http::header::HeaderMap::new();
+        let body = serde_json::json!({
+            "id": "AE_test456",
+            "type": "checkout.session.completed",
+            "data": {}
+        })
+        .to_string();
+
+        let event = Wave::new()
+            .get_webhook_event_type(&webhook_request_details(&headers, body.as_bytes()))
+            .unwrap();
+
+        assert_eq!(event, IncomingWebhookEvent::PaymentIntentSuccess);
+    }
+
+    fn signed_webhook_account(webhook_secret: &str) -> crypto::Encryptable<Secret<serde_json::Value>> {
+        let auth = serde_json::json!({
+            "auth_type": "SignatureKey",
+            "api_key": "wave_sn_prod_test",
+            "key1": webhook_secret,
+            "api_secret": "{}"
+        });
+        crypto::Encryptable::new(Secret::new(auth), Vec::new().into())
+    }
+
+    fn wave_signature_header(webhook_secret: &str, timestamp: &str, body: &str) -> String {
+        use common_utils::crypto::SignMessage;
+
+        let message = format!("{timestamp}{body}");
+        let signature = crypto::HmacSha256
+            .sign_message(webhook_secret.as_bytes(), message.as_bytes())
+            .unwrap();
+        format!("t={timestamp},v1={}", hex::encode(signature))
+    }
+
+    fn fresh_timestamp() -> String {
+        date_time::now_unix_timestamp().to_string()
+    }
+
+    async fn verify_wave_webhook(
+        header: Option<String>,
+        body: &str,
+        connector_account_details: crypto::Encryptable<Secret<serde_json::Value>>,
+    ) -> CustomResult<bool, errors::ConnectorError> {
+        let mut headers = actix_web::http::header::HeaderMap::new();
+        if let Some(header) = header {
+            headers.insert(
+                actix_web::http::header::HeaderName::from_static("wave-signature"),
+                actix_web::http::header::HeaderValue::from_str(&header).unwrap(),
+            );
+        }
+        let merchant_id = id_type::MerchantId::default();
+        Wave::new()
+            .verify_webhook_source(
+                &webhook_request_details(&headers, body.as_bytes()),
+                &merchant_id,
+                None,
+                connector_account_details,
+                "wave",
+            )
+            .await
+    }
+
+    #[tokio::test]
+    async fn test_webhook_signed_with_account_secret_is_verified() {
+        let body = r#"{"id":"AE_test789","type":"checkout.session.completed","data":{"id":"cos-test123"}}"#;
+        let header = wave_signature_header("whsec_test", &fresh_timestamp(), body);
+
+        let verified = verify_wave_webhook(Some(header), body, signed_webhook_account("whsec_test"))
+            .await
+            .unwrap();
+
+        assert!(verified);
+    }
+
+    #[tokio::test]
+    async fn test_webhook_signed_with_other_secret_is_rejected() {
+        let body = r#"{"id":"AE_test789","type":"checkout.session.completed","data":{"id":"cos-test123"}}"#;
+        let header = wave_signature_header("whsec_other", &fresh_timestamp(), body);
+
+        let verified = verify_wave_webhook(Some(header), body, signed_webhook_account("whsec_test"))
+            .await
+            .unwrap();
+
+        assert!(!verified);
+    }
+
+    #[tokio::test]
+    async fn test_webhook_with_stale_timestamp_is_rejected() {
+        let body = r#"{"id":"AE_test789","type":"checkout.session.completed","data":{"id":"cos-test123"}}"#;
+        let stale = date_time::now_unix_timestamp() - WAVE_WEBHOOK_TOLERANCE_SECONDS - 60;
+        let header = wave_signature_header("whsec_test", &stale.to_string(), body);
+
+        let verified = verify_wave_webhook(Some(header), body, signed_webhook_account("whsec_test"))
+            .await
+            .unwrap();
+
+        assert!(!verified);
+    }
+
+    #[tokio::test]
+    async fn test_webhook_without_signature_header_is_an_error() {
+        let body = r#"{"id":"AE_test789","type":"checkout.session.completed","data":{"id":"cos-test123"}}"#;
+
+        let error = verify_wave_webhook(None, body, signed_webhook_account("whsec_test"))
+            .await
+            .unwrap_err();
+
+        assert_eq!(
+            error.current_context(),
+            &errors::ConnectorError::WebhookSignatureNotFound
+        );
+    }
+
+    #[tokio::test]
+    async fn test_webhook_for_header_key_account_is_not_verified() {
+        let body = r#"{"id":"AE_test789","type":"checkout.session.completed","data":{"id":"cos-test123"}}"#;
+        let header = wave_signature_header("whsec_test", &fresh_timestamp(), body);
+        let account = crypto::Encryptable::new(
+            Secret::new(serde_json::json!({
+                "auth_type": "HeaderKey",
+                "api_key": "wave_sn_prod_test"
+            })),
+            Vec::new().into(),
+        );
+
+        let verified = verify_wave_webhook(Some(header), body, account).await.unwrap();
+
+        assert!(!verified);
+    }
+
+    #[test]
+    fn test_http_client_build_failure_is_reported_as_init_failure() {
+        // An invalid header value makes the builder fail deterministically
+        let builder = reqwest::Client::builder().user_agent("invalid\nagent");
+
+        let error = build_wave_http_client(builder).unwrap_err();
+
+        assert_eq!(
+            error.current_context(),
+            &errors::ConnectorError::ProcessingStepFailed(Some(
+                WAVE_HTTP_CLIENT_INIT_FAILED.into()
+            ))
+        );
+    }
+
+    #[test]
+    fn test_http_client_is_shared() {
+        let first = wave_http_client().unwrap();
+        let second = wave_http_client().unwrap();
+
+        assert!(std::ptr::eq(first, second));
+    }
+
+    #[test]
+    fn test_service_client_takes_limits_from_connector_config() {
+        let config = hyperswitch_domain_models::connector_endpoints::WaveConnectorConfig {
+            request_timeout_secs: Some(15),
+            max_retries: Some(5),
+            max_response_body_bytes: Some(2048),
+            max_concurrent_temporary_merchants: Some(2),
+            ..Default::default()
+        };
+
+        let configured = WaveServiceClient::new(&config).unwrap();
+        let defaults = WaveServiceClient::new(&Default::default()).unwrap();
+
+        assert_eq!(configured.max_retries, 5);
+        assert_eq!(configured.max_response_body_bytes, 2048);
+        assert_eq!(configured.temporary_merchant_limiter.max_in_flight, 2);
+        assert_eq!(defaults.max_retries, WAVE_DEFAULT_MAX_RETRIES);
+        assert_eq!(defaults.max_response_body_bytes, WAVE_DEFAULT_MAX_RESPONSE_BODY_BYTES);
+        assert_eq!(
+            defaults.temporary_merchant_limiter.max_in_flight,
+            WAVE_DEFAULT_MAX_CONCURRENT_TEMPORARY_MERCHANTS
+        );
+    }
+
+    #[test]
+    fn test_request_id_header_is_stable_per_attempt() {
+        let req: PaymentsSyncRouterData = build_router_data(PaymentsSyncData::default());
+        let request_id = |header_list: Vec<(String, Maskable<String>)>| {
+            header_list
+                .into_iter()
+                .find(|(name, _)| name == headers::X_REQUEST_ID)
+                .map(|(_, value)| value.into_inner())
+        };
+
+        let first = request_id(Wave::new().get_headers(&req, &Connectors::default()).unwrap());
+        let second = request_id(Wave::new().get_headers(&req, &Connectors::default()).unwrap());
+
+        assert_eq!(first, Some("pay_wave_test_1".to_string()));
+        assert_eq!(first, second);
+    }
+
+    #[test]
+    fn test_authorize_headers_carry_stable_idempotency_key() {
+        let req: PaymentsAuthorizeRouterData = build_router_data(authorize_request_data(1000));
+        let idempotency_key = |header_list: Vec<(String, Maskable<String>)>| {
+            header_list
+                .into_iter()
+                .find(|(name, _)| name == headers::IDEMPOTENCY_KEY)
+                .map(|(_, value)| value.into_inner())
+        };
+
+        let first = idempotency_key(Wave::new().get_headers(&req, &Connectors::default()).unwrap());
+        let second = idempotency_key(Wave::new().get_headers(&req, &Connectors::default()).unwrap());
+
+        assert_eq!(first, Some("pay_wave_test_1".to_string()));
+        assert_eq!(first, second);
+    }
+
+    fn search_session_json(id: &str, reference: &str, created_at: &str) -> serde_json::Value {
+        serde_json::json!({
+            "id": id,
+            "status": "completed",
+            "amount": "1000",
+            "currency": "XOF",
+            "reference": reference,
+            "created_at": created_at
+        })
+    }
+
+    #[test]
+    fn test_psync_without_session_id_searches_by_reference() {
+        let req: PaymentsSyncRouterData = build_router_data(PaymentsSyncData::default());
+
+        let url = Wave::new().get_url(&req, &Connectors::default()).unwrap();
+
+        assert_eq!(url, "checkout/sessions/search?reference=pay_wave_test_1");
+    }
+
+    #[test]
+    fn test_psync_by_reference_uses_latest_matching_session() {
+        let req: PaymentsSyncRouterData = build_router_data(PaymentsSyncData::default());
+        let response = Response {
+            headers: None,
+            response: serde_json::to_vec(&serde_json::json!({
+                "result": [
+                    search_session_json("cos-older", "pay_wave_test_1", "2026-10-01T10:00:00Z"),
+                    search_session_json("cos-latest", "pay_wave_test_1", "2026-10-01T10:05:00Z"),
+                    search_session_json("cos-other", "pay_wave_test_2", "2026-10-01T10:10:00Z")
+                ]
+            }))
+            .unwrap()
+            .into(),
+            status_code: 200,
+        };
+
+        let synced = Wave::new().handle_response(&req, None, response).unwrap();
+
+        assert_eq!(synced.status, AttemptStatus::Charged);
+        match synced.response.unwrap() {
+            PaymentsResponseData::TransactionResponse { resource_id, .. } => assert_eq!(
+                resource_id.get_connector_transaction_id().unwrap(),
+                "cos-latest"
+            ),
+            other => panic!("Expected a transaction response, got {other:?}"),
+        }
+    }
+
+    #[test]
+    fn test_psync_by_reference_without_match_is_missing_transaction() {
+        let req: PaymentsSyncRouterData = build_router_data(PaymentsSyncData::default());
+        let response = Response {
+            headers: None,
+            response: serde_json::to_vec(&serde_json::json!({ "result": [] }))
+                .unwrap()
+                .into(),
+            status_code: 200,
+        };
+
+        let error = Wave::new().handle_response(&req, None, response).unwrap_err();
+
+        assert_eq!(
+            error.current_context(),
+            &errors::ConnectorError::MissingConnectorTransactionID
+        );
+    }
+
+    #[tokio::test]
+    async fn test_update_verify_catches_ignored_fields() {
+        let (mock_server, base_url) = start_mock_server().await;
+        // The PUT echoes the requested name but the stored merchant keeps the old one
+        Mock::given(method("PUT"))
+            .and(path("/v1/aggregated_merchants/am-test123"))
+            .respond_with(
+                ResponseTemplate::new(200).set_body_json(aggregated_merchant_json("Boutique Thies")),
+            )
+            .expect(1)
+            .mount(&mock_server)
+            .await;
+        Mock::given(method("GET"))
+            .and(path("/v1/aggregated_merchants/am-test123"))
+            .respond_with(
+                ResponseTemplate::new(200).set_body_json(aggregated_merchant_json("Boutique Dakar")),
+            )
+            .expect(1)
+            .mount(&mock_server)
+            .await;
+
+        let error = WaveAggregatedMerchantService::update_aggregated_merchant(
+            &test_api_key(),
+            &base_url,
+            "am-test123",
+            wave::WaveAggregatedMerchantUpdateRequest {
+                name: Some("Boutique Thies".to_string()),
+                business_type: None,
+                business_registration_identifier: None,
+                business_sector: None,
+                website_url: None,
+                business_description: None,
+                manager_name: None,
+                clear_fields: Vec::new(),
+            },
+            true,
+        )
+        .await
+        .unwrap_err();
+
+        assert_eq!(
+            error.current_context(),
+            &errors::ConnectorError::from(wave::WaveAggregatedMerchantError::UpdateNotApplied {
+                merchant_id: "am-test123".to_string(),
+                fields: vec!["name".to_string()],
+            })
+        );
+    }
+
+    #[test]
+    fn test_build_common_headers() {
+        let auth_type = ConnectorAuthType::HeaderKey {
+            api_key: test_api_key(),
+        };
+
+        let with_content_type = Wave::new().build_common_headers(&auth_type, true).unwrap();
+        let without_content_type = Wave::new().build_common_headers(&auth_type, false).unwrap();
+
+        let find = |header_list: &[(String, Maskable<String>)], name: &str| {
+            header_list
+                .iter()
+                .find(|(header_name, _)| header_name == name)
+                .map(|(_, value)| value.clone())
+        };
+
+        let auth = find(&with_content_type, headers::AUTHORIZATION).unwrap();
+        assert!(auth.is_masked());
+        assert_eq!(auth.into_inner(), "Bearer wave_test_key");
+        assert_eq!(
+            find(&with_content_type, headers::ACCEPT).map(Maskable::into_inner),
+            Some("application/json".to_string())
+        );
+        assert_eq!(
+            find(&with_content_type, headers::CONTENT_TYPE).map(Maskable::into_inner),
+            Some("application/json".to_string())
+        );
+
+        assert!(find(&without_content_type, headers::CONTENT_TYPE).is_none());
+        assert!(find(&without_content_type, headers::AUTHORIZATION).unwrap().is_masked());
+    }
+
+    async fn mount_delete_response(mock_server: &MockServer, response: ResponseTemplate) {
+        Mock::given(method("DELETE"))
+            .and(path("/v1/aggregated_merchants/am-test123"))
+            .respond_with(response)
+            .expect(1)
+            .mount(mock_server)
+            .await;
+    }
+
+    #[tokio::test]
+    async fn test_delete_aggregated_merchant_success() {
+        let (mock_server, base_url) = start_mock_server().await;
+        mount_delete_response(&mock_server, ResponseTemplate::new(200)).await;
+
+        let result = WaveAggregatedMerchantService::delete_aggregated_merchant(
+            &test_api_key(),
+            &base_url,
+            "am-test123",
+            false,
+        )
+        .await;
+
+        assert!(result.is_ok());
+    }
+
+    #[tokio::test]
+    async fn test_delete_aggregated_merchant_already_gone_is_ok() {
+        let (mock_server, base_url) = start_mock_server().await;
+        mount_delete_response(
+            &mock_server,
+            ResponseTemplate::new(404).set_body_json(serde_json::json!({
+                "code": "AGGREGATED_MERCHANT_NOT_FOUND",
+                "message": "Aggregated merchant not found"
+            })),
+        )
+        .await;
+
+        let result = WaveAggregatedMerchantService::delete_aggregated_merchant(
+            &test_api_key(),
+            &base_url,
+            "am-test123",
+            false,
+        )
+        .await;
+
+        assert!(result.is_ok());
+    }
+
+    #[tokio::test]
+    async fn test_delete_aggregated_merchant_conflict() {
+        let (mock_server, base_url) = start_mock_server().await;
+        mount_delete_response(
+            &mock_server,
+            ResponseTemplate::new(409).set_body_json(serde_json::json!({
+                "code": "AGGREGATED_MERCHANT_IN_USE",
+                "message": "Aggregated merchant has active payments"
+            })),
+        )
+        .await;
+
+        let error = WaveAggregatedMerchantService::delete_aggregated_merchant(
+            &test_api_key(),
+            &base_url,
+            "am-test123",
+            false,
+        )
+        .await
+        .unwrap_err();
+
+        assert_eq!(
+            error.current_context(),
+            &errors::ConnectorError::from(wave::WaveAggregatedMerchantError::DeleteConflict {
+                merchant_id: "am-test123".to_string(),
+                reason: "Aggregated merchant has active payments".to_string(),
+            })
+        );
+    }
+
+    #[tokio::test]
+    async fn test_delete_aggregated_merchant_pre_check_refuses_active_merchant() {
+        let (mock_server, base_url) = start_mock_server().await;
+        Mock::given(method("GET"))
+            .and(path("/v1/aggregated_merchants/am-test123"))
+            .respond_with(
+                ResponseTemplate::new(200).set_body_json(aggregated_merchant_json("Boutique Dakar")),
+            )
+            .mount(&mock_server)
+            .await;
+        Mock::given(method("DELETE"))
+            .respond_with(ResponseTemplate::new(200))
+            .expect(0)
+            .mount(&mock_server)
+            .await;
+
+        let result = WaveAggregatedMerchantService::delete_aggregated_merchant(
+            &test_api_key(),
+            &base_url,
+            "am-test123",
+            true,
+        )
+        .await;
+
+        assert!(result.is_err());
+    }
+
+    #[tokio::test]
+    async fn test_delete_aggregated_merchant_pre_check_skips_missing_merchant() {
+        let (mock_server, base_url) = start_mock_server().await;
+        Mock::given(method("GET"))
+            .and(path("/v1/aggregated_merchants/am-test123"))
+            .respond_with(ResponseTemplate::new(404).set_body_json(serde_json::json!({
+                "code": "AGGREGATED_MERCHANT_NOT_FOUND",
+                "message": "Aggregated merchant not found"
+            })))
+            .mount(&mock_server)
+            .await;
+        Mock::given(method("DELETE"))
+            .respond_with(ResponseTemplate::new(200))
+            .expect(0)
+            .mount(&mock_server)
+            .await;
+
+        let result = WaveAggregatedMerchantService::delete_aggregated_merchant(
+            &test_api_key(),
+            &base_url,
+            "am-test123",
+            true,
+        )
+        .await;
+
+        assert!(result.is_ok());
+    }
+
+    #[tokio::test]
+    async fn test_delete_aggregated_merchant_pre_check_propagates_server_error() {
+        let (mock_server, base_url) = start_mock_server().await;
+        Mock::given(method("GET"))
+            .and(path("/v1/aggregated_merchants/am-test123"))
+            .respond_with(ResponseTemplate::new(500).set_body_json(serde_json::json!({
+                "code": "INTERNAL_ERROR",
+                "message": "Something went wrong"
+            })))
+            .mount(&mock_server)
+            .await;
+        Mock::given(method("DELETE"))
+            .respond_with(ResponseTemplate::new(200))
+            .expect(0)
+            .mount(&mock_server)
+            .await;
+
+        let result = WaveAggregatedMerchantService::delete_aggregated_merchant(
+            &test_api_key(),
+            &base_url,
+            "am-test123",
+            true,
+        )
+        .await;
+
+        assert!(result.is_err());
+    }
+
+    #[tokio::test]
+    async fn test_delete_aggregated_merchant_pre_check_propagates_auth_failure() {
+        let (mock_server, base_url) = start_mock_server().await;
+        Mock::given(method("GET"))
+            .and(path("/v1/aggregated_merchants/am-test123"))
+            .respond_with(ResponseTemplate::new(401).set_body_json(serde_json::json!({
+                "code": "UNAUTHORIZED",
+                "message": "Invalid API key"
+            })))
+            .mount(&mock_server)
+            .await;
+
+        let error = WaveAggregatedMerchantService::delete_aggregated_merchant(
+            &test_api_key(),
+            &base_url,
+            "am-test123",
+            true,
+        )
+        .await
+        .unwrap_err();
+
+        assert!(has_connector_error(
+            &error,
+            &errors::ConnectorError::from(wave::WaveAggregatedMerchantError::AuthenticationFailed)
+        ));
+    }
+
+    #[test]
+    fn test_build_wave_url_substitutes_placeholders() {
+        assert_eq!(
+            build_wave_url(WAVE_REFUND_FOR_TXN, &[("txn_id", "T_ABC123")]).unwrap(),
+            "v1/transactions/T_ABC123/refunds"
+        );
+    }
+
+    #[test]
+    fn test_build_wave_url_rejects_missing_substitution() {
+        // Mistyped placeholder name leaves {txn_id} in the template
+        let error = build_wave_url(WAVE_CANCEL_PAYMENT, &[("transaction_id", "T_ABC123")]).unwrap_err();
+        assert_eq!(error.current_context(), &errors::ConnectorError::RequestEncodingFailed);
+
+        // No value supplied at all
+        let error = build_wave_url(WAVE_REFUND_STATUS, &[]).unwrap_err();
+        assert_eq!(error.current_context(), &errors::ConnectorError::RequestEncodingFailed);
+    }
+
+    #[test]
+    fn test_build_wave_url_rejects_empty_id() {
+        let error = build_wave_url(WAVE_CHECKOUT_SESSION_STATUS, &[("session_id", "")]).unwrap_err();
+        assert_eq!(error.current_context(), &errors::ConnectorError::RequestEncodingFailed);
+    }
+
+    /// The service wraps the parsed Wave error in `ProcessingStepFailed`, so look through the whole chain
+    fn has_connector_error(
+        error: &error_stack::Report<errors::ConnectorError>,
+        expected: &errors::ConnectorError,
+    ) -> bool {
+        error
+            .frames()
+            .filter_map(|frame| frame.downcast_ref::<errors::ConnectorError>())
+            .any(|connector_error| connector_error == expected)
+    }
+
+    #[tokio::test]
+    async fn test_send_wave_request_reports_timeouts() {
+        let (mock_server, base_url) = start_mock_server().await;
+        Mock::given(method("GET"))
+            .respond_with(ResponseTemplate::new(200).set_delay(std::time::Duration::from_secs(2)))
+            .mount(&mock_server)
+            .await;
+        let client = reqwest::Client::builder()
+            .timeout(std::time::Duration::from_millis(100))
+            .build()
+            .unwrap();
+
+        let error = send_wave_request(client.get(base_url)).await.unwrap_err();
+
+        assert_eq!(error.current_context(), &errors::ConnectorError::RequestTimeoutReceived);
+    }
+
+    #[tokio::test]
+    async fn test_send_wave_request_reports_connection_failures_as_processing_failures() {
+        let (mock_server, base_url) = start_mock_server().await;
+        // Nothing listens on the address once the server is gone
+        drop(mock_server);
+
+        let error = send_wave_request(reqwest::Client::new().get(base_url))
+            .await
+            .unwrap_err();
+
+        assert_eq!(
+            error.current_context(),
+            &errors::ConnectorError::ProcessingStepFailed(None)
+        );
+    }
+
+    #[tokio::test]
+    async fn test_list_aggregated_merchants_encodes_the_cursor() {
+        let (mock_server, base_url) = start_mock_server().await;
+        // Decoded by the server, the cursor comes back whole instead of as extra parameters
+        Mock::given(method("GET"))
+            .and(path("/v1/aggregated_merchants"))
+            .and(query_param("limit", "50"))
+            .and(query_param("cursor", "cur_abc&limit=100#frag+x=y"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+                "aggregated_merchants": [],
+                "total_count": 0,
+                "next_cursor": null
+            })))
+            .expect(1)
+            .mount(&mock_server)
+            .await;
+
+        WaveAggregatedMerchantService::list_aggregated_merchants(
+            &test_api_key(),
+            &base_url,
+            None,
+            Some("cur_abc&limit=100#frag+x=y".to_string()),
+        )
+        .await
+        .unwrap();
+    }
+
+    #[tokio::test]
+    async fn test_list_aggregated_merchants_sends_pagination_params() {
+        let (mock_server, base_url) = start_mock_server().await;
+        Mock::given(method("GET"))
+            .and(path("/v1/aggregated_merchants"))
+            .and(query_param("limit", "2"))
+            .and(query_param("cursor", "cur_abc"))
+            .and(header("Authorization", "Bearer wave_test_key"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+                "aggregated_merchants": [aggregated_merchant_json("Boutique Dakar")],
+                "total_count": 3,
+                "next_cursor": "cur_def"
+            })))
+            .expect(1)
+            .mount(&mock_server)
+            .await;
+
+        let response = WaveAggregatedMerchantService::list_aggregated_merchants(
+            &test_api_key(),
+            &base_url,
+            Some(2),
+            Some("cur_abc".to_string()),
+        )
+        .await
+        .unwrap();
+
+        assert_eq!(response.aggregated_merchants.len(), 1);
+        assert_eq!(response.aggregated_merchants[0].id, "am-test123");
+        assert_eq!(response.total_count, Some(3));
+        assert_eq!(response.next_cursor, Some("cur_def".to_string()));
+    }
+
+    #[tokio::test]
+    async fn test_list_aggregated_merchants_defaults_page_size() {
+        let (mock_server, base_url) = start_mock_server().await;
+        Mock::given(method("GET"))
+            .and(path("/v1/aggregated_merchants"))
+            .and(query_param("limit", "50"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+                "aggregated_merchants": [aggregated_merchant_json("Boutique Dakar")]
+            })))
+            .expect(1)
+            .mount(&mock_server)
+            .await;
+
+        let response = WaveAggregatedMerchantService::list_aggregated_merchants(
+            &test_api_key(),
+            &base_url,
+            None,
+            None,
+        )
+        .await
+        .unwrap();
+
+        assert_eq!(response.aggregated_merchants.len(), 1);
+    }
+
+    #[tokio::test]
+    async fn test_list_aggregated_merchants_rejects_out_of_range_limit() {
+        let (mock_server, base_url) = start_mock_server().await;
+        Mock::given(method("GET"))
+            .and(path("/v1/aggregated_merchants"))
+            .respond_with(ResponseTemplate::new(200))
+            .expect(0)
+            .mount(&mock_server)
+            .await;
+
+        for limit in [0, WAVE_AGGREGATED_MERCHANT_LIST_MAX_LIMIT + 1] {
+            let error = WaveAggregatedMerchantService::list_aggregated_merchants(
+                &test_api_key(),
+                &base_url,
+                Some(limit),
+                None,
+            )
+            .await
+            .unwrap_err();
+
+            assert_eq!(
+                error.current_context(),
+                &errors::ConnectorError::InvalidDataFormat { field_name: "limit" }
+            );
+        }
+    }
+
+    #[tokio::test]
+    async fn test_list_aggregated_merchants_rate_limited() {
+        let (mock_server, base_url) = start_mock_server().await;
+        Mock::given(method("GET"))
+            .and(path("/v1/aggregated_merchants"))
+            .respond_with(ResponseTemplate::new(429).set_body_json(serde_json::json!({
+                "code": "RATE_LIMITED",
+                "message": "Too many requests"
+            })))
+            .mount(&mock_server)
+            .await;
+
+        let error = WaveAggregatedMerchantService::list_aggregated_merchants(
+            &test_api_key(),
+            &base_url,
+            None,
+            None,
+        )
+        .await
+        .unwrap_err();
+
+        assert!(has_connector_error(
+            &error,
+            &errors::ConnectorError::from(wave::WaveAggregatedMerchantError::RateLimitExceeded)
+        ));
+    }
+
+    #[tokio::test]
+    async fn test_create_aggregated_merchant_invalid_business_type() {
+        let (mock_server, base_url) = start_mock_server().await;
+        Mock::given(method("POST"))
+            .and(path("/v1/aggregated_merchants"))
+            .respond_with(ResponseTemplate::new(400).set_body_json(serde_json::json!({
+                "code": "INVALID_BUSINESS_TYPE",
+                "message": "Business type is not allowed"
+            })))
+            .expect(1)
+            .mount(&mock_server)
+            .await;
+
+        let request = wave::WaveAggregatedMerchantRequestBuilder::new()
+            .name("Boutique Dakar")
+            .business_description("Payment processing for Boutique Dakar")
+            .build()
+            .unwrap();
+        let error =
+            WaveAggregatedMerchantService::create_aggregated_merchant(&test_api_key(), &base_url, request)
+                .await
+                .unwrap_err();
+
+        assert_eq!(error.current_context(), &errors::ConnectorError::ProcessingStepFailed(None));
+        assert!(has_connector_error(
+            &error,
+            &errors::ConnectorError::from(wave::WaveAggregatedMerchantError::InvalidConfiguration {
+                details: "Business type is not allowed".to_string(),
+            })
+        ));
+    }
+
+    #[tokio::test]
+    async fn test_get_aggregated_merchant_unauthorized() {
+        let (mock_server, base_url) = start_mock_server().await;
+        Mock::given(method("GET"))
+            .and(path("/v1/aggregated_merchants/am-test123"))
+            .respond_with(ResponseTemplate::new(401).set_body_json(serde_json::json!({
+                "code": "UNAUTHORIZED",
+                "message": "Invalid API key"
+            })))
+            .mount(&mock_server)
+            .await;
+
+        let error =
+            WaveAggregatedMerchantService::get_aggregated_merchant(&test_api_key(), &base_url, "am-test123")
+                .await
+                .unwrap_err();
+
+        assert!(has_connector_error(&error, &errors::ConnectorError::AuthenticationFailed));
+    }
+
+    #[tokio::test]
+    async fn test_send_and_parse_success_and_error() {
+        let (mock_server, base_url) = start_mock_server().await;
+        Mock::given(method("GET"))
+            .and(path("/ok"))
+            .and(header_exists("X-Request-Id"))
+            .respond_with(
+                ResponseTemplate::new(200).set_body_json(aggregated_merchant_json("Boutique Dakar")),
+            )
+            .mount(&mock_server)
+            .await;
+        Mock::given(method("GET"))
+            .and(path("/missing"))
+            .respond_with(ResponseTemplate::new(404).set_body_json(serde_json::json!({
+                "code": "AGGREGATED_MERCHANT_NOT_FOUND",
+                "message": "Aggregated merchant not found"
+            })))
+            .mount(&mock_server)
+            .await;
+
+        let merchant = send_and_parse::<wave::WaveAggregatedMerchant>(
+            wave_http_client().unwrap().get(format!("{base_url}ok")),
+        )
+        .await
+        .unwrap();
+        assert_eq!(merchant.name, "Boutique Dakar");
+
+        let error = send_and_parse::<wave::WaveAggregatedMerchant>(
+            wave_http_client().unwrap().get(format!("{base_url}missing")),
+        )
+        .await
+        .unwrap_err();
+        assert_eq!(error.current_context(), &errors::ConnectorError::ProcessingStepFailed(None));
+        assert!(has_connector_error(
+            &error,
+            &errors::ConnectorError::from(wave::WaveAggregatedMerchantError::MerchantNotFound {
+                merchant_id: "unknown".to_string(),
+            })
+        ));
+    }
+
+    fn authorized_router_data(connector_meta_data: serde_json::Value) -> PaymentsAuthorizeRouterData {
+        let mut router_data: PaymentsAuthorizeRouterData = build_router_data(authorize_request_data(1000));
+        router_data.status = AttemptStatus::AuthenticationPending;
+        router_data.connector_meta_data = Some(Secret::new(connector_meta_data));
+        router_data.response = Ok(PaymentsResponseData::TransactionResponse {
+            resource_id: ResponseId::ConnectorTransactionId("cos-test123".to_string()),
+            redirection_data: Box::new(None),
+            mandate_reference: Box::new(None),
+            connector_metadata: None,
+            network_txn_id: None,
+            connector_response_reference_id: None,
+            incremental_authorization_allowed: None,
+            charges: None,
+        });
+        router_data
+    }
+
+    fn auto_create_auth() -> wave::WaveAuthType {
+        wave::WaveAuthType {
+            api_key: test_api_key(),
+            aggregated_merchants_enabled: true,
+            auto_create_aggregated_merchant: true,
+            default_business_type: wave::WaveBusinessType::default(),
+            cache_ttl_seconds: 3600,
+            webhook_secret: None,
+        }
+    }
+
+    #[test]
+    fn test_refund_webhook_resolves_to_refund_reference() {
+        let headers = actix_web::http::header::HeaderMap::new();
+        let body = serde_json::json!({
+            "id": "AE_test789",
+            "type": "refund.completed",
+            "data": {
+                "id": "rf-test123",
+                "transaction_id": "T_test123"
+            }
+        })
+        .to_string();
+
+        let reference = Wave::new()
+            .get_webhook_object_reference_id(&webhook_request_details(&headers, body.as_bytes()))
+            .unwrap();
+
+        assert!(matches!(
+            reference,
+            ObjectReferenceId::RefundId(api_models::webhooks::RefundIdType::ConnectorRefundId(ref id))
+                if id == "rf-test123"
+        ));
+    }
+
+    #[test]
+    fn test_refund_webhook_resource_exposes_transaction_id() {
+        let headers = actix_web::http::header::HeaderMap::new();
+        let body = serde_json::json!({
+            "id": "AE_test789",
+            "type": "refund.completed",
+            "data": {
+                "id": "rf-test123",
+                "transaction_id": "T_test123"
+            }
+        })
+        .to_string();
+
+        let resource = Wave::new()
+            .get_webhook_resource_object(&webhook_request_details(&headers, body.as_bytes()))
+            .unwrap();
+        assert_eq!(
+            resource.masked_serialize().unwrap(),
+            serde_json::json!({ "id": "rf-test123", "transaction_id": "T_test123" })
+        );
+    }
+
+    #[test]
+    fn test_checkout_webhook_resolves_to_payment_reference() {
+        let headers = actix_web::http::header::HeaderMap::new();
+        let body = serde_json::json!({
+            "id": "AE_test456",
+            "type": "checkout.session.completed",
+            "data": { "id": "cos-test123" }
+        })
+        .to_string();
+
+        let reference = Wave::new()
+            .get_webhook_object_reference_id(&webhook_request_details(&headers, body.as_bytes()))
+            .unwrap();
+
+        assert!(matches!(
+            reference,
+            ObjectReferenceId::PaymentId(api_models::payments::PaymentIdType::ConnectorTransactionId(ref id))
+                if id == "cos-test123"
+        ));
+    }
+
+    #[test]
+    fn test_specifications_advertise_mobile_pay_without_mandates() {
+        let supported_payment_methods = Wave::new().get_supported_payment_methods().unwrap();
+        let mobile_pay = supported_payment_methods
+            .get(&PaymentMethod::Wallet)
+            .and_then(|payment_method_types| payment_method_types.get(&enums::PaymentMethodType::MobilePay))
+            .unwrap();
+
+        assert_eq!(mobile_pay.mandates, enums::FeatureStatus::NotSupported);
+        assert_eq!(mobile_pay.refunds, enums::FeatureStatus::Supported);
+        assert_eq!(mobile_pay.supported_capture_methods, vec![enums::CaptureMethod::Automatic]);
+        assert_eq!(
+            Wave::new().get_supported_webhook_flows(),
+            Some(&[enums::EventClass::Payments, enums::EventClass::Refunds][..])
+        );
+        assert_eq!(Wave::new().get_connector_about().unwrap().display_name, "Wave");
+    }
+
+    #[test]
+    fn test_authorize_amount_is_validated_against_metadata_limits() {
+        let connector_meta_data = Some(Secret::new(serde_json::json!({
+            "min_amount": 500,
+            "max_amount": 10_000
+        })));
+        let validate = |amount| {
+            Wave::new().validate_authorize_amount(MinorUnit::new(amount), connector_meta_data.clone())
+        };
+
+        assert!(validate(500).is_ok());
+        assert!(validate(10_000).is_ok());
+        assert_eq!(
+            validate(499).unwrap_err().current_context(),
+            &errors::ConnectorError::NotSupported {
+                message: "Amount 499 is below the Wave minimum of 500".to_string(),
+                connector: "wave",
+            }
+        );
+        assert_eq!(
+            validate(10_001).unwrap_err().current_context(),
+            &errors::ConnectorError::NotSupported {
+                message: "Amount 10001 is above the Wave maximum of 10000".to_string(),
+                connector: "wave",
+            }
+        );
+    }
+
+    #[tokio::test]
+    async fn test_get_multiple_refund_statuses_reports_each_refund() {
+        let (mock_server, base_url) = start_mock_server().await;
+        Mock::given(method("GET"))
+            .and(path("/v1/refunds/rf-found123"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+                "id": "rf-found123",
+                "status": "completed",
+                "amount": "500",
+                "currency": "XOF",
+                "transaction_id": "T_test123"
+            })))
+            .expect(1)
+            .mount(&mock_server)
+            .await;
+        Mock::given(method("GET"))
+            .and(path("/v1/refunds/rf-missing123"))
+            .respond_with(ResponseTemplate::new(404).set_body_json(serde_json::json!({
+                "code": "REFUND_NOT_FOUND",
+                "message": "Refund not found"
+            })))
+            .expect(1)
+            .mount(&mock_server)
+            .await;
+
+        let refund_ids = vec!["rf-found123".to_string(), "rf-missing123".to_string()];
+        let results =
+            WaveRefundService::get_multiple_refund_statuses(&test_api_key(), &base_url, &refund_ids)
+                .await;
+
+        assert_eq!(results.len(), 2);
+        let (found_id, found) = &results[0];
+        assert_eq!(found_id, "rf-found123");
+        assert_eq!(found.as_ref().unwrap().status, wave::WaveRefundStatus::Completed);
+        let (missing_id, missing) = &results[1];
+        assert_eq!(missing_id, "rf-missing123");
+        assert!(missing.is_err());
+    }
+
+    #[tokio::test]
+    async fn test_temporary_merchant_creation_respects_concurrency_cap() {
+        let (mock_server, base_url) = start_mock_server().await;
+        Mock::given(method("POST"))
+            .and(path("/v1/aggregated_merchants"))
+            .respond_with(
+                ResponseTemplate::new(201)
+                    .set_body_json(aggregated_merchant_json("Boutique Dakar"))
+                    .set_delay(std::time::Duration::from_millis(200)),
+            )
+            .expect(2)
+            .mount(&mock_server)
+            .await;
+
+        let router_data: PaymentsAuthorizeRouterData = build_router_data(authorize_request_data(1000));
+        let auth = auto_create_auth();
+        let limiter = WaveCreationLimiter::new(2);
+
+        let results = futures::future::join_all((0..10).map(|_| {
+            WaveAggregatedMerchantResolver::create_temporary_aggregated_merchant(
+                &auth,
+                &base_url,
+                &router_data,
+                &limiter,
+            )
+        }))
+        .await;
+
+        let created = results.iter().filter(|result| matches!(result, Ok(Some(_)))).count();
+        let skipped = results
+            .iter()
+            .filter(|result| **result == Err(TemporaryCreationError::LimitReached))
+            .count();
+        assert_eq!(created, 2);
+        assert_eq!(skipped, 8);
+        assert!(limiter.try_acquire().is_some());
+    }
+
+    fn webhook_endpoint_json() -> serde_json::Value {
+        serde_json::json!({
+            "id": "wh-test123",
+            "url": "https://hyperswitch.example/webhooks/wave",
+            "events": ["checkout.session.completed", "refund.completed"]
+        })
+    }
+
+    #[tokio::test]
+    async fn test_register_webhook_returns_endpoint_id() {
+        let (mock_server, base_url) = start_mock_server().await;
+        Mock::given(method("POST"))
+            .and(path("/v1/webhooks"))
+            .and(body_partial_json(serde_json::json!({
+                "url": "https://hyperswitch.example/webhooks/wave",
+                "events": ["checkout.session.completed", "refund.completed"]
+            })))
+            .respond_with(ResponseTemplate::new(201).set_body_json(webhook_endpoint_json()))
+            .expect(1)
+            .mount(&mock_server)
+            .await;
+
+        let endpoint = WaveWebhookService::register_webhook(
+            &test_api_key(),
+            &base_url,
+            "https://hyperswitch.example/webhooks/wave",
+            vec![
+                wave::WaveWebhookEvent::CheckoutCompleted,
+                wave::WaveWebhookEvent::RefundCompleted,
+            ],
+        )
+        .await
+        .unwrap();
+
+        assert_eq!(endpoint.id, "wh-test123");
+        let metadata = wave::with_webhook_id(None, &endpoint.id);
+        assert_eq!(metadata.peek()["webhook_id"], "wh-test123");
+    }
+
+    #[tokio::test]
+    async fn test_list_webhooks_returns_registered_endpoints() {
+        let (mock_server, base_url) = start_mock_server().await;
+        Mock::given(method("GET"))
+            .and(path("/v1/webhooks"))
+            .respond_with(
+                ResponseTemplate::new(200)
+                    .set_body_json(serde_json::json!({ "webhooks": [webhook_endpoint_json()] })),
+            )
+            .expect(1)
+            .mount(&mock_server)
+            .await;
+
+        let webhooks = WaveWebhookService::list_webhooks(&test_api_key(), &base_url)
+            .await
+            .unwrap();
+
+        assert_eq!(webhooks.len(), 1);
+        assert_eq!(webhooks[0].id, "wh-test123");
+        assert_eq!(webhooks[0].events[1], wave::WaveWebhookEvent::RefundCompleted);
+    }
+
+    #[tokio::test]
+    async fn test_delete_webhook_is_idempotent() {
+        let (mock_server, base_url) = start_mock_server().await;
+        Mock::given(method("DELETE"))
+            .and(path("/v1/webhooks/wh-test123"))
+            .respond_with(ResponseTemplate::new(204))
+            .expect(1)
+            .mount(&mock_server)
+            .await;
+        Mock::given(method("DELETE"))
+            .and(path("/v1/webhooks/wh-gone123"))
+            .respond_with(ResponseTemplate::new(404))
+            .expect(1)
+            .mount(&mock_server)
+            .await;
+
+        WaveWebhookService::delete_webhook(&test_api_key(), &base_url, "wh-test123")
+            .await
+            .unwrap();
+        WaveWebhookService::delete_webhook(&test_api_key(), &base_url, "wh-gone123")
+            .await
+            .unwrap();
+    }
+
+    #[test]
+    fn test_session_flow_is_explicitly_not_implemented() {
+        let req: PaymentsSessionRouterData = build_router_data(PaymentsSessionData {
+            amount: 1000,
+            currency: Currency::XOF,
+            country: None,
+            surcharge_details: None,
+            order_details: None,
+            email: None,
+            minor_amount: MinorUnit::new(1000),
+            apple_pay_recurring_details: None,
+            customer_name: None,
+            order_tax_amount: None,
+            shipping_cost: None,
+            metadata: None,
+        });
+
+        let error = Wave::new().build_request(&req, &Connectors::default()).unwrap_err();
+
+        assert_eq!(
+            error.current_context(),
+            &errors::ConnectorError::NotImplemented("Session flow".to_string())
+        );
+    }
+
+    #[test]
+    fn test_payment_method_token_flow_is_explicitly_not_implemented() {
+        let req: TokenizationRouterData = build_router_data(PaymentMethodTokenizationData {
+            payment_method_data: mobile_pay_redirect(),
+            browser_info: None,
+            currency: Currency::XOF,
+            amount: Some(1000),
+            split_payments: None,
+            customer_acceptance: None,
+            setup_future_usage: None,
+            setup_mandate_details: None,
+            mandate_id: None,
+        });
+
+        let error = Wave::new().build_request(&req, &Connectors::default()).unwrap_err();
+
+        assert_eq!(
+            error.current_context(),
+            &errors::ConnectorError::NotImplemented("Payment Method Token flow".to_string())
+        );
+    }
+
+    #[test]
+    fn test_wave_connector_config_flows_from_toml() {
+        let connectors: Connectors = toml::from_str(
+            r#"
+            [wave]
+            base_url = "https://api.wave.com/"
+            merchant_service_base_url = "https://merchants.wave.com/"
+            request_timeout_secs = 15
+            max_retries = 5
+            "#,
+        )
+        .unwrap();
+
+        assert_eq!(Wave::new().base_url(&connectors), "https://api.wave.com/");
+        assert_eq!(
+            connectors.wave.get_merchant_service_base_url(),
+            "https://merchants.wave.com/"
+        );
+        assert_eq!(connectors.wave.request_timeout_secs, Some(15));
+        assert_eq!(connectors.wave.max_retries, Some(5));
+    }
+
+    #[test]
+    fn test_wave_merchant_service_base_url_defaults_to_base_url() {
+        let connectors: Connectors =
+            toml::from_str("[wave]\nbase_url = \"https://api.wave.com/\"\n").unwrap();
+
+        assert_eq!(
+            connectors.wave.get_merchant_service_base_url(),
+            "https://api.wave.com/"
+        );
+        assert_eq!(connectors.wave.max_retries, None);
+    }
+
+    #[tokio::test]
+    async fn test_oversized_response_body_is_rejected() {
+        let (mock_server, base_url) = start_mock_server().await;
+        Mock::given(method("GET"))
+            .and(path("/huge"))
+            .respond_with(
+                ResponseTemplate::new(200)
+                    .set_body_string("a".repeat(WAVE_DEFAULT_MAX_RESPONSE_BODY_BYTES + 1)),
+            )
+            .mount(&mock_server)
+            .await;
+
+        let error = send_and_parse::<wave::WaveAggregatedMerchant>(
+            wave_http_client().unwrap().get(format!("{base_url}huge")),
+        )
+        .await
+        .unwrap_err();
+        assert_eq!(
+            error.current_context(),
+            &errors::ConnectorError::ResponseDeserializationFailed
+        );
+    }
+
+    #[tokio::test]
+    async fn test_response_body_within_limit_is_read() {
+        let (mock_server, base_url) = start_mock_server().await;
+        Mock::given(method("GET"))
+            .and(path("/small"))
+            .respond_with(ResponseTemplate::new(200).set_body_string("0123456789"))
+            .mount(&mock_server)
+            .await;
+        let get = || wave_http_client().unwrap().get(format!("{base_url}small"));
+
+        let body = read_wave_response_body(get().send().await.unwrap(), 10)
+            .await
+            .unwrap();
+        assert_eq!(body, b"0123456789");
+
+        let error = read_wave_response_body(get().send().await.unwrap(), 9)
+            .await
+            .unwrap_err();
+        assert_eq!(
+            error.current_context(),
+            &errors::ConnectorError::ResponseDeserializationFailed
+        );
+    }
+
+    #[tokio::test]
+    async fn test_suspended_aggregated_merchant_is_not_used() {
+        let (mock_server, base_url) = start_mock_server().await;
+        let mut merchant = aggregated_merchant_json("Boutique Dakar");
+        merchant["status"] = serde_json::json!("suspended");
+        Mock::given(method("GET"))
+            .and(path("/v1/aggregated_merchants/am-test123"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(merchant))
+            .mount(&mock_server)
+            .await;
+
+        let auth = wave::WaveAuthType {
+            auto_create_aggregated_merchant: false,
+            ..auto_create_auth()
+        };
+        let router_data = authorized_router_data(serde_json::json!({
+            "aggregated_merchant_id": "am-test123",
+            "auto_create_aggregated_merchant": false
+        }));
+
+        let usable =
+            WaveAggregatedMerchantResolver::validate_aggregated_merchant(&auth, &base_url, "am-test123")
+                .await
+                .unwrap();
+        assert!(!usable);
+
+        let resolved =
+            WaveAggregatedMerchantResolver::resolve_aggregated_merchant(&auth, &base_url, &router_data)
+                .await
+                .unwrap();
+        assert_eq!(resolved, None);
+    }
+
+    async fn resolve_with_suspended_merchant(
+        on_resolution_failure: Option<&str>,
+    ) -> CustomResult<Option<String>, errors::ConnectorError> {
+        let (mock_server, base_url) = start_mock_server().await;
+        let mut merchant = aggregated_merchant_json("Boutique Dakar");
+        merchant["status"] = serde_json::json!("suspended");
+        Mock::given(method("GET"))
+            .and(path("/v1/aggregated_merchants/am-test123"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(merchant))
+            .mount(&mock_server)
+            .await;
+        let mut metadata = serde_json::json!({
+            "aggregated_merchant_id": "am-test123",
+            "auto_create_aggregated_merchant": false
+        });
+        if let Some(policy) = on_resolution_failure {
+            metadata["on_resolution_failure"] = serde_json::json!(policy);
+        }
+
+        WaveAggregatedMerchantResolver::resolve_aggregated_merchant(
+            &auto_create_auth(),
+            &base_url,
+            &authorized_router_data(metadata),
+        )
+        .await
+    }
+
+    #[tokio::test]
+    async fn test_unresolvable_merchant_degrades_by_default() {
+        assert_eq!(resolve_with_suspended_merchant(None).await.unwrap(), None);
+        assert_eq!(
+            resolve_with_suspended_merchant(Some("degrade")).await.unwrap(),
+            None
+        );
+    }
+
+    #[tokio::test]
+    async fn test_unresolvable_merchant_fails_payment_when_configured() {
+        let error = resolve_with_suspended_merchant(Some("fail_payment"))
+            .await
+            .unwrap_err();
+
+        assert_eq!(
+            error.current_context(),
+            &errors::ConnectorError::ProcessingStepFailed(Some(
+                "Aggregated merchant am-test123 not found or not accessible".into()
+            ))
+        );
+    }
+
+    #[tokio::test]
+    async fn test_failed_auto_creation_follows_resolution_policy() {
+        let (mock_server, base_url) = start_mock_server().await;
+        Mock::given(method("POST"))
+            .and(path("/v1/aggregated_merchants"))
+            .respond_with(ResponseTemplate::new(500))
+            .mount(&mock_server)
+            .await;
+        let resolve = |policy: &str| {
+            let router_data = authorized_router_data(serde_json::json!({
+                "auto_create_aggregated_merchant": true,
+                "business_description": "Online retail",
+                "on_resolution_failure": policy
+            }));
+            let base_url = base_url.clone();
+            async move {
+                WaveAggregatedMerchantResolver::resolve_aggregated_merchant(
+                    &auto_create_auth(),
+                    &base_url,
+                    &router_data,
+                )
+                .await
+            }
+        };
+
+        assert_eq!(resolve("degrade").await.unwrap(), None);
+        assert!(resolve("fail_payment").await.is_err());
+    }
+
+    fn cancel_router_data(status: AttemptStatus) -> PaymentsCancelRouterData {
+        let mut req: PaymentsCancelRouterData = build_router_data(PaymentsCancelData {
+            connector_transaction_id: "cos-test123".to_string(),
+            currency: Some(Currency::XOF),
+            minor_amount: Some(MinorUnit::new(1000)),
+            ..Default::default()
+        });
+        req.status = status;
+        req
+    }
+
+    fn refund_sync_router_data(connector_refund_id: &str) -> RefundSyncRouterData {
+        let mut req: RefundSyncRouterData = build_router_data(refunds_request_data(500));
+        req.request.connector_refund_id = Some(connector_refund_id.to_string());
+        req
+    }
+
+    #[test]
+    fn test_refund_sync_url_uses_valid_refund_id() {
+        let url = Wave::new()
+            .get_url(&refund_sync_router_data("rf-test123"), &Connectors::default())
+            .unwrap();
+
+        assert_eq!(url, "v1/refunds/rf-test123");
+    }
+
+    #[test]
+    fn test_refund_sync_url_rejects_malformed_refund_id() {
+        let error = Wave::new()
+            .get_url(&refund_sync_router_data("cos-test123"), &Connectors::default())
+            .unwrap_err();
+
+        assert_eq!(
+            error.current_context(),
+            &errors::ConnectorError::InvalidDataFormat {
+                field_name: "connector_refund_id"
+            }
+        );
+    }
+
+    #[tokio::test]
+    async fn test_get_refund_status_rejects_malformed_refund_id_without_calling_wave() {
+        let (mock_server, base_url) = start_mock_server().await;
+        Mock::given(method("GET"))
+            .respond_with(ResponseTemplate::new(404))
+            .expect(0)
+            .mount(&mock_server)
+            .await;
+
+        let error = WaveRefundService::get_refund_status(&test_api_key(), &base_url, "T_ABC123")
+            .await
+            .unwrap_err();
+
+        assert_eq!(
+            error.current_context(),
+            &errors::ConnectorError::InvalidDataFormat {
+                field_name: "connector_refund_id"
+            }
+        );
+    }
+
+    #[test]
+    fn test_void_of_completed_payment_is_rejected_before_sending() {
+        let error = Wave::new()
+            .build_request(&cancel_router_data(AttemptStatus::Charged), &Connectors::default())
+            .unwrap_err();
+
+        assert_eq!(
+            error.current_context(),
+            &errors::ConnectorError::InvalidDataFormat { field_name: "status" }
+        );
+        assert!(format!("{error:?}").contains("payment cannot be voided in status charged"));
+    }
+
+    #[test]
+    fn test_void_of_pending_payment_builds_request() {
+        let request = Wave::new()
+            .build_request(
+                &cancel_router_data(AttemptStatus::AuthenticationPending),
+                &Connectors::default(),
+            )
+            .unwrap();
+
+        assert!(request.is_some());
+    }
+
+    /// Minimal subscriber keeping every span's name and recorded fields, for asserting on instrumentation
+    #[derive(Default)]
+    struct SpanRecorder {
+        spans: std::sync::Mutex<Vec<(&'static tracing::Metadata<'static>, Vec<(String, String)>)>>,
+        stack: std::sync::Mutex<Vec<u64>>,
+    }
+
+    struct FieldCollector<'a>(&'a mut Vec<(String, String)>);
+
+    impl tracing::field::Visit for FieldCollector<'_> {
+        fn record_str(&mut self, field: &tracing::field::Field, value: &str) {
+            self.0.push((field.name().to_string(), value.to_string()));
+        }
+
+        fn record_debug(&mut self, field: &tracing::field::Field, value: &dyn std::fmt::Debug) {
+            self.0.push((field.name().to_string(), format!("{value:?}")));
+        }
+    }
+
+    impl tracing::Subscriber for SpanRecorder {
+        fn enabled(&self, _metadata: &tracing::Metadata<'_>) -> bool {
+            true
+        }
+
+        fn new_span(&self, attrs: &tracing::span::Attributes<'_>) -> tracing::span::Id {
+            let mut fields = Vec::new();
+            attrs.record(&mut FieldCollector(&mut fields));
+            let mut spans = self.spans.lock().unwrap();
+            spans.push((attrs.metadata(), fields));
+            tracing::span::Id::from_u64(spans.len() as u64)
+        }
+
+        fn record(&self, span: &tracing::span::Id, values: &tracing::span::Record<'_>) {
+            let mut spans = self.spans.lock().unwrap();
+            if let Some((_, fields)) = spans.get_mut(span.into_u64() as usize - 1) {
+                values.record(&mut FieldCollector(fields));
+            }
+        }
+
+        fn record_follows_from(&self, _span: &tracing::span::Id, _follows: &tracing::span::Id) {}
+
+        fn event(&self, _event: &tracing::Event<'_>) {}
+
+        fn enter(&self, span: &tracing::span::Id) {
+            self.stack.lock().unwrap().push(span.into_u64());
+        }
+
+        fn exit(&self, _span: &tracing::span::Id) {
+            self.stack.lock().unwrap().pop();
+        }
+
+        fn current_span(&self) -> tracing::span::Current {
+            let current = self.stack.lock().unwrap().last().copied();
+            match current {
+                Some(id) => {
+                    let metadata = self.spans.lock().unwrap()[id as usize - 1].0;
+                    tracing::span::Current::new(tracing::span::Id::from_u64(id), metadata)
+                }
+                None => tracing::span::Current::none(),
+            }
+        }
+    }
+
+    #[test]
+    fn test_flow_spans_record_flow_name_and_http_status() {
+        let recorder = std::sync::Arc::new(SpanRecorder::default());
+        let response = Response {
+            headers: None,
+            response: serde_json::to_vec(&serde_json::json!({
+                "id": "cos-test123",
+                "status": "cancelled"
+            }))
+            .unwrap()
+            .into(),
+            status_code: 200,
+        };
+
+        tracing::subscriber::with_default(recorder.clone(), || {
+            let req = cancel_router_data(AttemptStatus::Pending);
+            Wave::new().build_request(&req, &Connectors::default()).unwrap();
+            Wave::new().handle_response(&req, None, response).unwrap();
+        });
+
+        let spans = recorder.spans.lock().unwrap();
+        let flow_spans: Vec<_> = spans
+            .iter()
+            .filter(|(metadata, _)| ["build_request", "handle_response"].contains(&metadata.name()))
+            .collect();
+        assert_eq!(flow_spans.len(), 2);
+        for (_, fields) in &flow_spans {
+            assert!(fields.contains(&("flow".to_string(), "void".to_string())));
+        }
+        assert!(flow_spans[1]
+            .1
+            .contains(&("http_status".to_string(), "200".to_string())));
+    }
+
+    #[test]
+    fn test_handled_void_response_is_a_success() {
+        let response = Response {
+            headers: None,
+            response: serde_json::to_vec(&serde_json::json!({
+                "id": "cos-test123",
+                "status": "cancelled"
+            }))
+            .unwrap()
+            .into(),
+            status_code: 200,
+        };
+
+        let router_data =
+            Wave::new().handle_response(&cancel_router_data(AttemptStatus::Pending), None, response);
+
+        assert_eq!(get_wave_flow_response_status(&router_data), "success");
+    }
+
+    fn wave_error_http_response(status_code: u16, body: serde_json::Value) -> Response {
+        Response {
+            headers: None,
+            response: serde_json::to_vec(&body).unwrap().into(),
+            status_code,
+        }
+    }
+
+    #[test]
+    fn test_service_unavailable_maps_to_retryable_gsm_code() {
+        let response = wave_error_http_response(
+            503,
+            serde_json::json!({ "message": "Upstream mobile money operator unavailable" }),
+        );
+
+        let error = ConnectorIntegration::<Authorize, PaymentsAuthorizeData, PaymentsResponseData>::get_5xx_error_response(
+            Wave::new(),
+            response,
+            None,
+        )
+        .unwrap();
+
+        assert_eq!(error.code, WAVE_SERVICE_UNAVAILABLE_CODE);
+        assert_eq!(error.message, WAVE_SERVICE_UNAVAILABLE_MESSAGE);
+        assert_eq!(error.status_code, 503);
+        assert_eq!(error.attempt_status, None);
+        assert_eq!(
+            error.reason.as_deref(),
+            Some("Upstream mobile money operator unavailable")
+        );
+    }
+
+    #[test]
+    fn test_internal_error_maps_to_retryable_gsm_code_distinct_from_unavailable() {
+        let response = wave_error_http_response(500, serde_json::json!({}));
+
+        let error = ConnectorIntegration::<Execute, RefundsData, RefundsResponseData>::get_5xx_error_response(
+            Wave::new(),
+            response,
+            None,
+        )
+        .unwrap();
+
+        assert_eq!(error.code, WAVE_INTERNAL_ERROR_CODE);
+        assert_eq!(error.message, WAVE_INTERNAL_ERROR_MESSAGE);
+        assert_eq!(error.reason, None);
+    }
+
+    #[test]
+    fn test_bad_request_keeps_wave_error_code() {
+        let response = wave_error_http_response(
+            400,
+            serde_json::json!({ "code": "INVALID_AMOUNT", "message": "Amount is invalid" }),
+        );
+
+        let error = ConnectorIntegration::<Authorize, PaymentsAuthorizeData, PaymentsResponseData>::get_error_response(
+            Wave::new(),
+            response,
+            None,
+        )
+        .unwrap();
+
+        assert_eq!(error.code, "INVALID_AMOUNT");
+    }
+
+    #[test]
+    fn test_handled_response_carrying_an_error_is_a_failure() {
+        let mut router_data = cancel_router_data(AttemptStatus::Pending);
+        router_data.response = Err(ErrorResponse::default());
+
+        assert_eq!(get_wave_flow_response_status(&Ok(router_data)), "failure");
+        assert_eq!(
+            get_wave_flow_response_status::<Void, PaymentsCancelData, PaymentsResponseData>(&Err(
+                errors::ConnectorError::ResponseDeserializationFailed.into()
+            )),
+            "failure"
+        );
+    }
+
+    #[test]
+    fn test_error_response_reads_nested_error_envelope() {
+        let response = Response {
+            headers: None,
+            response: serde_json::to_vec(&serde_json::json!({
+                "error": {
+                    "code": "CHECKOUT_SESSION_NOT_FOUND",
+                    "message": "Checkout session not found"
+                }
+            }))
+            .unwrap()
+            .into(),
+            status_code: 404,
+        };
+
+        let error = Wave::new().build_error_response(response, None).unwrap();
+
+        assert_eq!(error.code, "CHECKOUT_SESSION_NOT_FOUND");
+        assert_eq!(error.message, "Checkout session not found");
+        assert_eq!(error.reason, None);
+    }
+
+    #[tokio::test]
+    async fn test_update_sends_cleared_website_url_as_null() {
+        let (mock_server, base_url) = start_mock_server().await;
+        Mock::given(method("PUT"))
+            .and(path("/v1/aggregated_merchants/am-test123"))
+            .and(body_partial_json(serde_json::json!({ "website_url": null })))
+            .respond_with(
+                ResponseTemplate::new(200).set_body_json(aggregated_merchant_json("Boutique Dakar")),
+            )
+            .expect(1)
+            .mount(&mock_server)
+            .await;
+        Mock::given(method("GET"))
+            .and(path("/v1/aggregated_merchants/am-test123"))
+            .respond_with(
+                ResponseTemplate::new(200).set_body_json(aggregated_merchant_json("Boutique Dakar")),
+            )
+            .mount(&mock_server)
+            .await;
+
+        let updated = WaveAggregatedMerchantService::update_aggregated_merchant(
+            &test_api_key(),
+            &base_url,
+            "am-test123",
+            wave::WaveAggregatedMerchantUpdateRequest {
+                name: None,
+                business_type: None,
+                business_registration_identifier: None,
+                business_sector: None,
+                website_url: None,
+                business_description: None,
+                manager_name: None,
+                clear_fields: vec![wave::WaveAggregatedMerchantClearableField::WebsiteUrl],
+            },
+            true,
+        )
+        .await
+        .unwrap();
+
+        assert_eq!(updated.website_url, None);
+    }
+
+    #[test]
+    fn test_resolver_backoff_jitter_stays_within_exponential_bounds() {
+        use rand::SeedableRng;
+
+        let delays = |seed| {
+            let mut rng = rand::rngs::StdRng::seed_from_u64(seed);
+            (1..=8)
+                .map(|attempt| {
+                    wave_backoff_with_jitter(
+                        WAVE_RESOLVER_RETRY_BASE_DELAY,
+                        WAVE_RESOLVER_RETRY_MAX_DELAY,
+                        attempt,
+                        &mut rng,
+                    )
+                })
+                .collect::<Vec<_>>()
+        };
+
+        let first = delays(7);
+        for (attempt, delay) in (1..=8).zip(&first) {
+            let ceiling = std::cmp::min(
+                WAVE_RESOLVER_RETRY_BASE_DELAY * 2_u32.pow(attempt - 1),
+                WAVE_RESOLVER_RETRY_MAX_DELAY,
+            );
+            assert!(*delay <= ceiling, "attempt {attempt}: {delay:?} > {ceiling:?}");
+        }
+        // Same seed, same delays, so the spread is reproducible under test
+        assert_eq!(first, delays(7));
+        assert_ne!(first, delays(8));
+    }
+
+    fn pre_processing_router_data(
+        aggregated_merchants_enabled: bool,
+        connector_meta_data: serde_json::Value,
+    ) -> PaymentsPreProcessingRouterData {
+        let request = PaymentsPreProcessingData::try_from(authorize_request_data(1000)).unwrap();
+        let mut router_data: PaymentsPreProcessingRouterData = build_router_data(request);
+        router_data.connector_auth_type = ConnectorAuthType::BodyKey {
+            api_key: test_api_key(),
+            key1: Secret::new(
+                serde_json::json!({
+                    "enabled": aggregated_merchants_enabled,
+                    "auto_create_on_profile_creation": false,
+                    "default_business_type": "ecommerce",
+                    "cache_ttl_seconds": 3600
+                })
+                .to_string(),
+            ),
+        };
+        router_data.connector_meta_data = Some(Secret::new(connector_meta_data));
+        router_data
+    }
+
+    fn aggregated_merchant_response(status: &str) -> Response {
+        let mut merchant = aggregated_merchant_json("Boutique Dakar");
+        merchant["status"] = serde_json::json!(status);
+        Response {
+            headers: None,
+            response: serde_json::to_vec(&merchant).unwrap().into(),
+            status_code: 200,
+        }
+    }
+
+    #[test]
+    fn test_pre_processing_looks_up_configured_aggregated_merchant() {
+        let req = pre_processing_router_data(
+            true,
+            serde_json::json!({ "aggregated_merchant_id": "am-test123" }),
+        );
+
+        let request = Wave::new()
+            .build_request(&req, &Connectors::default())
+            .unwrap()
+            .unwrap();
+
+        assert_eq!(request.method, Method::Get);
+        assert_eq!(request.url, wave_url("v1/aggregated_merchants/am-test123"));
+    }
+
+    #[test]
+    fn test_pre_processing_is_skipped_without_aggregated_merchant() {
+        let disabled = pre_processing_router_data(
+            false,
+            serde_json::json!({ "aggregated_merchant_id": "am-test123" }),
+        );
+        let unconfigured = pre_processing_router_data(true, serde_json::json!({}));
+
+        for req in [disabled, unconfigured] {
+            assert!(Wave::new()
+                .build_request(&req, &Connectors::default())
+                .unwrap()
+                .is_none());
+        }
+    }
+
+    #[test]
+    fn test_pre_processing_confirms_active_aggregated_merchant() {
+        let req = pre_processing_router_data(
+            true,
+            serde_json::json!({ "aggregated_merchant_id": "am-test123" }),
+        );
+
+        let router_data = Wave::new()
+            .handle_response(&req, None, aggregated_merchant_response("active"))
+            .unwrap();
+
+        assert_eq!(router_data.preprocessing_id, Some("am-test123".to_string()));
+        assert!(router_data.response.is_ok());
+    }
+
+    #[test]
+    fn test_pre_processing_leaves_suspended_aggregated_merchant_unconfirmed() {
+        let req = pre_processing_router_data(
+            true,
+            serde_json::json!({ "aggregated_merchant_id": "am-test123" }),
+        );
+
+        let router_data = Wave::new()
+            .handle_response(&req, None, aggregated_merchant_response("suspended"))
+            .unwrap();
+
+        assert_eq!(router_data.preprocessing_id, None);
+        let error = router_data.response.unwrap_err();
+        assert_eq!(error.message, "Aggregated merchant am-test123 is suspended");
+        assert_eq!(error.attempt_status, None);
+    }
+
+    fn authorize_event(request: serde_json::Value) -> ConnectorEvent {
+        ConnectorEvent::new(
+            id_type::TenantId::try_from_string("public".to_string()).unwrap(),
+            "wave".to_string(),
+            "Authorize",
+            request,
+            "https://api.wave.com/checkout/sessions".to_string(),
+            Method::Post,
+            "pay_wave_test".to_string(),
+            id_type::MerchantId::default(),
+            None,
+            0,
+            None,
+            None,
+            200,
+        )
+    }
+
+    #[test]
+    fn test_event_request_body_masks_customer_pii() {
+        use std::str::FromStr;
+
+        let mut router_data: PaymentsAuthorizeRouterData = build_router_data(authorize_request_data(1000));
+        router_data.request.email = Some(common_utils::pii::Email::from_str("awa.diop@example.sn").unwrap());
+        router_data.connector_meta_data = Some(Secret::new(serde_json::json!({
+            "aggregated_merchant_id": "am-test123"
+        })));
+        let request = checkout_request_for(&router_data).unwrap();
+        // Same masking the router applies when it records the request body
+        let mut event = authorize_event(masking::masked_serialize(&request).unwrap());
+
+        apply_wave_event_verbosity(&mut event, router_data.connector_meta_data.as_ref());
+
+        let emitted = serde_json::to_string(&event).unwrap();
+        assert!(emitted.contains("am-test123"));
+        assert!(!emitted.contains("awa.diop@example.sn"));
+    }
+
+    #[test]
+    fn test_event_request_body_can_be_excluded() {
+        let connector_meta_data = Secret::new(serde_json::json!({
+            "include_request_body_in_events": false
+        }));
+        let mut event = authorize_event(serde_json::json!({ "amount": "1000" }));
+
+        apply_wave_event_verbosity(&mut event, Some(&connector_meta_data));
+
+        let emitted = serde_json::to_value(&event).unwrap();
+        assert_eq!(
+            emitted["request"],
+            serde_json::json!({ "request_type": "OMITTED" }).to_string()
+        );
+    }
+
+    #[test]
+    fn test_event_request_body_is_excluded_on_error_responses() {
+        let connector_meta_data = Secret::new(serde_json::json!({
+            "aggregated_merchant_id": "am-test123",
+            "include_request_body_in_events": false
+        }));
+        let mut event = authorize_event(serde_json::json!({
+            "amount": "1000",
+            "aggregated_merchant_id": "am-test123"
+        }));
+
+        // Same order as the router: settings first, then the flow's error builder
+        ConnectorCommon::apply_event_verbosity(Wave::new(), &mut event, Some(&connector_meta_data));
+        let response = wave_error_http_response(
+            503,
+            serde_json::json!({ "message": "Upstream mobile money operator unavailable" }),
+        );
+        ConnectorIntegration::<Authorize, PaymentsAuthorizeData, PaymentsResponseData>::get_5xx_error_response(
+            Wave::new(),
+            response,
+            Some(&mut event),
+        )
+        .unwrap();
+
+        let emitted = serde_json::to_value(&event).unwrap();
+        assert_eq!(
+            emitted["request"],
+            serde_json::json!({ "request_type": "OMITTED" }).to_string()
+        );
+        assert!(!emitted.to_string().contains("am-test123"));
+    }
+
+    /// Connector URLs for every flow are the configured base URL followed by the filled template
+    fn wave_url(path: &str) -> String {
+        format!("{}{}", Wave::new().base_url(&Connectors::default()), path)
+    }
+
+    fn sync_router_data(connector_transaction_id: ResponseId) -> PaymentsSyncRouterData {
+        build_router_data(PaymentsSyncData {
+            connector_transaction_id,
+            ..Default::default()
+        })
+    }
+
+    fn void_router_data(connector_transaction_id: &str) -> PaymentsCancelRouterData {
+        let mut req: PaymentsCancelRouterData = build_router_data(PaymentsCancelData {
+            connector_transaction_id: connector_transaction_id.to_string(),
+            ..Default::default()
+        });
+        req.status = AttemptStatus::Pending;
+        req
+    }
+
+    fn refund_router_data<F>(connector_transaction_id: &str) -> RefundsRouterData<F> {
+        let mut req: RefundsRouterData<F> = build_router_data(refunds_request_data(500));
+        req.request.connector_transaction_id = connector_transaction_id.to_string();
+        req
+    }
+
+    #[test]
+    fn test_authorize_url() {
+        let req: PaymentsAuthorizeRouterData = build_router_data(authorize_request_data(1000));
+
+        assert_eq!(
+            Wave::new().get_url(&req, &Connectors::default()).unwrap(),
+            wave_url("checkout/sessions")
+        );
+    }
+
+    #[test]
+    fn test_psync_url_by_session_id() {
+        let req = sync_router_data(ResponseId::ConnectorTransactionId("cos-18qq25rgr100a".to_string()));
+
+        assert_eq!(
+            Wave::new().get_url(&req, &Connectors::default()).unwrap(),
+            wave_url("checkout/sessions/cos-18qq25rgr100a")
+        );
+    }
+
+    #[test]
+    fn test_psync_url_encodes_session_id() {
+        let req = sync_router_data(ResponseId::ConnectorTransactionId("cos-18qq%25rgr".to_string()));
+
+        assert_eq!(
+            Wave::new().get_url(&req, &Connectors::default()).unwrap(),
+            wave_url("checkout/sessions/cos-18qq%2525rgr")
+        );
+    }
+
+    #[test]
+    fn test_psync_url_by_reference_encodes_reference() {
+        let mut req = sync_router_data(ResponseId::NoResponseId);
+        req.connector_request_reference_id = "order 42&retry=1".to_string();
+        req.attempt_id = "order 42&retry=1".to_string();
+
+        assert_eq!(
+            Wave::new().get_url(&req, &Connectors::default()).unwrap(),
+            wave_url("checkout/sessions/search?reference=order%2042%26retry%3D1")
+        );
+    }
+
+    #[test]
+    fn test_void_url() {
+        assert_eq!(
+            Wave::new()
+                .get_url(&void_router_data("T_ABC123"), &Connectors::default())
+                .unwrap(),
+            wave_url("v1/transactions/T_ABC123/cancel")
+        );
+        assert_eq!(
+            Wave::new()
+                .get_url(&void_router_data("T_ABC:123"), &Connectors::default())
+                .unwrap(),
+            wave_url("v1/transactions/T_ABC%3A123/cancel")
+        );
+    }
+
+    #[test]
+    fn test_refund_url() {
+        let req: RefundsRouterData<Execute> = refund_router_data("T_ABC123");
+
+        assert_eq!(
+            Wave::new().get_url(&req, &Connectors::default()).unwrap(),
+            wave_url("v1/transactions/T_ABC123/refunds")
+        );
+    }
+
+    #[test]
+    fn test_refund_sync_url() {
+        let mut req: RefundSyncRouterData = refund_router_data("T_ABC123");
+        req.request.connector_refund_id = Some("rf-18qq+25".to_string());
+
+        assert_eq!(
+            Wave::new().get_url(&req, &Connectors::default()).unwrap(),
+            wave_url("v1/refunds/rf-18qq%2B25")
+        );
+    }
+
+    #[test]
+    fn test_ids_that_could_leave_their_segment_are_rejected() {
+        let req = sync_router_data(ResponseId::ConnectorTransactionId("cos-1/../refunds".to_string()));
+        assert!(Wave::new().get_url(&req, &Connectors::default()).is_err());
+
+        assert!(Wave::new()
+            .get_url(&void_router_data("T_ABC?force=1"), &Connectors::default())
+            .is_err());
+    }
+
+    #[test]
+    fn test_aggregated_merchant_urls() {
+        let aggregated_merchant_id = wave::WaveAggregatedMerchantId::new("am-test+123").unwrap();
+
+        for template in [
+            WAVE_AGGREGATED_MERCHANT_BY_ID,
+            WAVE_AGGREGATED_MERCHANT_UPDATE,
+            WAVE_AGGREGATED_MERCHANT_DELETE,
+        ] {
+            assert_eq!(
+                build_wave_url(template, &[("id", aggregated_merchant_id.as_ref())]).unwrap(),
+                "v1/aggregated_merchants/am-test%2B123"
+            );
+        }
+        assert_eq!(WAVE_AGGREGATED_MERCHANT_LIST, "v1/aggregated_merchants");
+        assert_eq!(WAVE_AGGREGATED_MERCHANT_CREATE, "v1/aggregated_merchants");
+    }
+
+    #[test]
+    fn test_webhook_urls() {
+        assert_eq!(
+            build_wave_url(WAVE_WEBHOOK_BY_ID, &[("webhook_id", "wh-18qq25")]).unwrap(),
+            "v1/webhooks/wh-18qq25"
+        );
+        assert_eq!(WAVE_WEBHOOKS, "v1/webhooks");
+    }
+}
@@ -1,24 +1,35 @@
+use api_models::{
+    payments::PaymentIdType,
+    webhooks::{IncomingWebhookEvent, ObjectReferenceId, RefundIdType},
+};
 use common_enums::{enums as api_enums, AttemptStatus, RefundStatus};
 use common_utils::{
-    pii::Email,
+    pii::{Email, SecretSerdeValue},
     request::Method,
     types::MinorUnit,
 };
 use hyperswitch_domain_models::{
-    router_data::{ConnectorAuthType, RouterData},
+    payment_method_data::{MobilePaymentData, PaymentMethodData, WalletData},
+    router_data::{ConnectorAuthType, ErrorResponse, RouterData},
     router_flow_types::{Execute},
-    router_request_types::{ResponseId},
-    router_response_types::{PaymentsResponseData, RefundsResponseData, RedirectForm},
+    router_request_types::{PaymentsPreProcessingData, PaymentsSyncData, ResponseId},
+    router_response_types::{
+        PaymentsResponseData, PreprocessingResponseId, RefundsResponseData, RedirectForm,
+    },
     types::{
-        PaymentsAuthorizeRouterData, PaymentsCancelRouterData, RefundsRouterData,
+        PaymentsAuthorizeRouterData, PaymentsCancelRouterData, PaymentsPreProcessingRouterData,
+        PaymentsSyncRouterData, RefundsRouterData,
     },
 };
 use hyperswitch_interfaces::{
     api, 
+    consts::NO_ERROR_CODE,
     errors::ConnectorError,
 };
 use masking::{Secret, PeekInterface};
 use serde::{Deserialize, Serialize};
+use sha2::Digest;
+use time::PrimitiveDateTime;
 use url::Url;
 
 
@@ -28,14 +39,17 @@ use crate::{
 };
 
 // Business types supported by Wave for aggregated merchants
-#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, strum::Display)]
 #[serde(rename_all = "lowercase")]
+#[strum(serialize_all = "lowercase")]
 pub enum WaveBusinessType {
     Ecommerce,
     Mobile,
     Pos,
     Marketplace,
     Subscription,
+    // Categories Wave adds later land here instead of failing the whole response
+    #[serde(other)]
     Other,
 }
 
@@ -65,6 +79,101 @@ impl Default for WaveAggregatedMerchantConfig {
     }
 }
 
+/// Ids are substituted into URL paths, so reject anything that could not be a single path segment
+fn is_valid_wave_id(id: &str) -> bool {
+    !id.is_empty() && !id.contains(|c: char| c == '/' || c == '?' || c == '#' || c.is_whitespace())
+}
+
+/// Wave checkout session id
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct WaveSessionId(String);
+
+impl WaveSessionId {
+    pub fn new(id: impl Into<String>) -> Result<Self, error_stack::Report<ConnectorError>> {
+        let id = id.into();
+        if !is_valid_wave_id(&id) {
+            return Err(ConnectorError::MissingConnectorTransactionID.into());
+        }
+        Ok(Self(id))
+    }
+}
+
+/// Wave transaction id, used for cancellations and refunds
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct WaveTransactionId(String);
+
+impl WaveTransactionId {
+    pub fn new(id: impl Into<String>) -> Result<Self, error_stack::Report<ConnectorError>> {
+        let id = id.into();
+        if !is_valid_wave_id(&id) {
+            return Err(ConnectorError::MissingConnectorTransactionID.into());
+        }
+        Ok(Self(id))
+    }
+}
+
+/// Wave refund id, always of the form `rf-xxxxxxxxx`
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct WaveRefundId(String);
+
+impl WaveRefundId {
+    pub fn new(id: impl Into<String>) -> Result<Self, error_stack::Report<ConnectorError>> {
+        let id = id.into();
+        if !is_valid_wave_id(&id) {
+            return Err(ConnectorError::MissingConnectorRefundID.into());
+        }
+        // Anything else is not a Wave refund and would only come back as a 404
+        if !id.starts_with("rf-") || id.len() < 4 {
+            return Err(error_stack::report!(ConnectorError::InvalidDataFormat {
+                field_name: "connector_refund_id",
+            })
+            .attach_printable(format!("{id} is not a Wave refund id")));
+        }
+        Ok(Self(id))
+    }
+}
+
+/// Wave aggregated merchant id, always of the form `am-xxxxxxxxx`
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct WaveAggregatedMerchantId(String);
+
+impl WaveAggregatedMerchantId {
+    pub fn new(id: impl Into<String>) -> Result<Self, error_stack::Report<ConnectorError>> {
+        let id = id.into();
+        if !is_valid_wave_id(&id) || !id.starts_with("am-") || id.len() < 4 {
+            return Err(ConnectorError::InvalidConnectorConfig {
+                config: "Invalid aggregated merchant ID format",
+            }
+            .into());
+        }
+        Ok(Self(id))
+    }
+}
+
+impl AsRef<str> for WaveSessionId {
+    fn as_ref(&self) -> &str {
+        &self.0
+    }
+}
+
+impl AsRef<str> for WaveTransactionId {
+    fn as_ref(&self) -> &str {
+        &self.0
+    }
+}
+
+impl AsRef<str> for WaveRefundId {
+    fn as_ref(&self) -> &str {
+        &self.0
+    }
+}
+
+impl AsRef<str> for WaveAggregatedMerchantId {
+    fn as_ref(&self) -> &str {
+        &self.0
+    }
+}
+
 pub struct WaveRouterData<T> {
     pub amount: MinorUnit,
     pub router_data: T,
@@ -98,6 +207,30 @@ pub struct WaveAuthType {
     pub auto_create_aggregated_merchant: bool,
     pub default_business_type: WaveBusinessType,
     pub cache_ttl_seconds: u64,
+    /// Signing secret for incoming webhooks; only set by the `SignatureKey` arm
+    pub webhook_secret: Option<Secret<String>>,
+}
+
+impl WaveAuthType {
+    fn with_enhanced_config(
+        api_key: &Secret<String>,
+        enhanced_config: &Secret<String>,
+        webhook_secret: Option<Secret<String>>,
+    ) -> Self {
+        // Support enhanced configuration via the config field
+        let enhanced_config = serde_json::from_str::<WaveAggregatedMerchantConfig>(enhanced_config.peek())
+            .ok()
+            .unwrap_or_default();
+
+        Self {
+            api_key: api_key.to_owned(),
+            aggregated_merchants_enabled: enhanced_config.enabled,
+            auto_create_aggregated_merchant: enhanced_config.auto_create_on_profile_creation,
+            default_business_type: enhanced_config.default_business_type,
+            cache_ttl_seconds: enhanced_config.cache_ttl_seconds,
+            webhook_secret,
+        }
+    }
 }
 
 impl TryFrom<&ConnectorAuthType> for WaveAuthType {
@@ -110,21 +243,21 @@ impl TryFrom<&ConnectorAuthType> for WaveAuthType {
                 auto_create_aggregated_merchant: false,
                 default_business_type: WaveBusinessType::default(),
                 cache_ttl_seconds: 3600, // 1 hour default cache TTL
+                webhook_secret: None,
             }),
             ConnectorAuthType::BodyKey { api_key, key1 } => {
-                // Support enhanced configuration via key1 field
-                let enhanced_config = serde_json::from_str::<WaveAggregatedMerchantConfig>(key1.peek())
-                    .ok()
-                    .unwrap_or_default();
-                
-                Ok(Self {
-                    api_key: api_key.to_owned(),
-                    aggregated_merchants_enabled: enhanced_config.enabled,
-                    auto_create_aggregated_merchant: enhanced_config.auto_create_on_profile_creation,
-                    default_business_type: enhanced_config.default_business_type,
-                    cache_ttl_seconds: enhanced_config.cache_ttl_seconds,
-                })
+                Ok(Self::with_enhanced_config(api_key, key1, None))
             },
+            // key1 carries the webhook signing secret, api_secret the enhanced configuration
+            ConnectorAuthType::SignatureKey {
+                api_key,
+                key1,
+                api_secret,
+            } => Ok(Self::with_enhanced_config(
+                api_key,
+                api_secret,
+                Some(key1.to_owned()),
+            )),
             _ => Err(ConnectorError::FailedToObtainAuthType.into()),
         }
     }
@@ -136,10 +269,178 @@ pub struct WaveCheckoutSessionRequest {
     pub currency: String,
     pub error_url: Option<String>,
     pub success_url: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub reference: Option<String>,
     pub aggregated_merchant_id: Option<String>, // New field for aggregated merchant support
     #[serde(skip_serializing_if = "Option::is_none")]
     pub customer: Option<WaveCustomer>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub locale: Option<String>,
+    /// Only this mobile number may pay the session, set when the payer's wallet number is known
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub restrict_payer_mobile: Option<Secret<String>>,
+    /// Share of the payment kept by the platform, only for marketplace aggregated merchants
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub platform_commission: Option<WavePlatformCommission>,
+    /// Mobile-money rail the checkout page offers first
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub preferred_provider: Option<WavePaymentProvider>,
+}
+
+impl WaveCheckoutSessionRequest {
+    /// Bare checkout for an amount, with success and error URLs derived from the return URL. The
+    /// `TryFrom` on the router data starts from this and fills in the rest through the builders.
+    /// Plain HTTP return URLs are only accepted for localhost when `allow_local_http` is set
+    pub fn new(
+        amount: MinorUnit,
+        currency: api_enums::Currency,
+        return_url: &str,
+        allow_local_http: bool,
+    ) -> Result<Self, error_stack::Report<ConnectorError>> {
+        validate_wave_currency(currency)?;
+        validate_wave_return_url(return_url, allow_local_http)?;
+        Ok(Self {
+            amount: get_wave_amount(amount, "amount")?,
+            currency: currency.to_string(),
+            error_url: Some(append_wave_return_status(return_url, WAVE_RETURN_STATUS_ERROR)),
+            success_url: Some(append_wave_return_status(return_url, WAVE_RETURN_STATUS_SUCCESS)),
+            reference: None,
+            aggregated_merchant_id: None,
+            customer: None,
+            locale: None,
+            restrict_payer_mobile: None,
+            platform_commission: None,
+            preferred_provider: None,
+        })
+    }
+
+    /// Replace the derived success and error URLs, held to the same rules as the return URL
+    pub fn with_redirect_urls(
+        mut self,
+        success_url: String,
+        error_url: String,
+        allow_local_http: bool,
+    ) -> Result<Self, error_stack::Report<ConnectorError>> {
+        validate_wave_redirect_url(&success_url, "success_url", allow_local_http)?;
+        validate_wave_redirect_url(&error_url, "error_url", allow_local_http)?;
+        self.success_url = Some(success_url);
+        self.error_url = Some(error_url);
+        Ok(self)
+    }
+
+    pub fn with_reference(mut self, reference: Option<String>) -> Self {
+        self.reference = reference;
+        self
+    }
+
+    pub fn with_customer(mut self, customer: Option<WaveCustomer>) -> Self {
+        self.customer = customer;
+        self
+    }
+
+    pub fn with_aggregated_merchant(mut self, aggregated_merchant_id: Option<String>) -> Self {
+        self.aggregated_merchant_id = aggregated_merchant_id;
+        self
+    }
+
+    pub fn with_locale(mut self, locale: Option<String>) -> Self {
+        self.locale = locale;
+        self
+    }
+
+    pub fn with_restrict_payer_mobile(mut self, restrict_payer_mobile: Option<Secret<String>>) -> Self {
+        self.restrict_payer_mobile = restrict_payer_mobile;
+        self
+    }
+
+    pub fn with_platform_commission(
+        mut self,
+        platform_commission: Option<WavePlatformCommission>,
+    ) -> Self {
+        self.platform_commission = platform_commission;
+        self
+    }
+
+    pub fn with_preferred_provider(mut self, preferred_provider: Option<WavePaymentProvider>) -> Self {
+        self.preferred_provider = preferred_provider;
+        self
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct WavePlatformCommission {
+    pub amount: String,
+}
+
+/// Marketplace commission configured on the connector, a fixed minor amount or basis points of the payment
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum WaveMarketplaceCommission {
+    Amount(i64),
+    Bps(u32),
+}
+
+const WAVE_BPS_DENOMINATOR: i64 = 10_000;
+
+/// Commission to send with a checkout, rejecting one that is negative or larger than the payment itself
+pub fn get_wave_platform_commission(
+    total: MinorUnit,
+    commission: Option<&WaveMarketplaceCommission>,
+) -> Result<Option<WavePlatformCommission>, error_stack::Report<ConnectorError>> {
+    let Some(commission) = commission else {
+        return Ok(None);
+    };
+    let total = total.get_amount_as_i64();
+    let commission_amount = match commission {
+        WaveMarketplaceCommission::Amount(amount) => *amount,
+        WaveMarketplaceCommission::Bps(bps) => i64::from(*bps)
+            .checked_mul(total)
+            .map(|scaled| scaled / WAVE_BPS_DENOMINATOR)
+            .unwrap_or(i64::MAX),
+    };
+    let exceeds_total = matches!(commission, WaveMarketplaceCommission::Bps(bps) if i64::from(*bps) > WAVE_BPS_DENOMINATOR)
+        || commission_amount < 0
+        || commission_amount > total;
+    if exceeds_total {
+        return Err(error_stack::report!(ConnectorError::InvalidDataFormat {
+            field_name: "marketplace_commission",
+        })
+        .attach_printable(format!(
+            "Commission {commission:?} is not within the payment total {total}"
+        )));
+    }
+    Ok(Some(WavePlatformCommission {
+        amount: commission_amount.to_string(),
+    }))
+}
+
+/// Mobile-money rails a merchant can hint as the payer's preferred provider at checkout
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum WavePaymentProvider {
+    Wave,
+    OrangeMoney,
+    FreeMoney,
+    MtnMomo,
+    MoovMoney,
+}
+
+/// Preferred provider configured on the connector. Kept as a string in the metadata so that an
+/// unknown provider is reported here rather than discarding every other Wave setting.
+pub fn get_wave_preferred_provider(
+    metadata: Option<&WaveConnectorMetadata>,
+) -> Result<Option<WavePaymentProvider>, error_stack::Report<ConnectorError>> {
+    let Some(provider) = metadata.and_then(|m| non_blank(m.preferred_provider.as_deref())) else {
+        return Ok(None);
+    };
+    serde_json::from_value(serde_json::Value::String(provider.trim().to_lowercase()))
+        .map(Some)
+        .map_err(|_| {
+            error_stack::report!(ConnectorError::InvalidDataFormat {
+                field_name: "preferred_provider",
+            })
+            .attach_printable(format!("{provider} is not a provider Wave supports"))
+        })
 }
 
 #[derive(Debug, Serialize)]
@@ -150,20 +451,200 @@ pub struct WaveCustomer {
     pub email: Option<Email>,
 }
 
+/// Payment methods Wave checkout accepts, listed in the error for anything else
+const WAVE_SUPPORTED_PAYMENT_METHODS: &str =
+    "wallet (mobile_pay_redirect) and mobile_payment (direct_carrier_billing)";
+
+/// Payer's wallet number for the payment methods Wave accepts, rejecting all other methods
+pub fn get_wave_payer_mobile(
+    payment_method_data: &PaymentMethodData,
+) -> Result<Option<Secret<String>>, error_stack::Report<ConnectorError>> {
+    match payment_method_data {
+        PaymentMethodData::Wallet(WalletData::MobilePayRedirect(_)) => Ok(None),
+        PaymentMethodData::MobilePayment(MobilePaymentData::DirectCarrierBilling {
+            msisdn, ..
+        }) => Ok(Some(Secret::new(msisdn.clone()))),
+        _ => Err(ConnectorError::NotImplemented(format!(
+            "Payment method through Wave, supported methods are {WAVE_SUPPORTED_PAYMENT_METHODS}"
+        ))
+        .into()),
+    }
+}
+
+/// Largest amount we will ever put in a Wave request body, far above any real mobile money limit
+const WAVE_MAX_REQUEST_AMOUNT: i64 = 1_000_000_000_000;
+
+/// Amount as Wave expects it in request bodies, rejecting non-positive and absurdly large values
+/// as an invalid `field_name`
+pub fn get_wave_amount(
+    amount: MinorUnit,
+    field_name: &'static str,
+) -> Result<String, error_stack::Report<ConnectorError>> {
+    let value = amount.get_amount_as_i64();
+    if value <= 0 || value > WAVE_MAX_REQUEST_AMOUNT {
+        return Err(error_stack::report!(ConnectorError::InvalidDataFormat { field_name })
+            .attach_printable(format!("{field_name} {value} is outside the range Wave accepts")));
+    }
+    Ok(value.to_string())
+}
+
+/// The checkout amount must be the payment's minor amount unchanged; a drift here means the
+/// `WaveRouterData` conversion no longer matches what the router stored for the payment
+pub fn reconcile_wave_checkout_amount(
+    request: &WaveCheckoutSessionRequest,
+    expected_amount: MinorUnit,
+) -> Result<(), error_stack::Report<ConnectorError>> {
+    let expected_amount = expected_amount.get_amount_as_i64();
+    if request.amount.parse::<i64>().ok() == Some(expected_amount) {
+        return Ok(());
+    }
+    router_env::logger::error!(
+        "Wave checkout amount {} does not match the payment amount {}",
+        request.amount,
+        expected_amount
+    );
+    Err(error_stack::report!(ConnectorError::InvalidDataFormat {
+        field_name: "amount",
+    })
+    .attach_printable(format!(
+        "Checkout amount {} does not reconcile with the payment amount {expected_amount}",
+        request.amount
+    )))
+}
+
+/// Wave only moves whole units of zero-decimal currencies such as XOF, where the minor amount is
+/// the amount Wave expects. Any other currency is turned away before an amount is sent
+pub fn validate_wave_currency(
+    currency: api_enums::Currency,
+) -> Result<(), error_stack::Report<ConnectorError>> {
+    if currency.number_of_digits_after_decimal_point() != 0 {
+        return Err(error_stack::report!(ConnectorError::CurrencyNotSupported {
+            message: currency.to_string(),
+            connector: "wave",
+        }));
+    }
+    Ok(())
+}
+
+/// Default per-transaction limits Wave applies to XOF mobile money, in minor units
+pub const WAVE_DEFAULT_MIN_AMOUNT: i64 = 100;
+pub const WAVE_DEFAULT_MAX_AMOUNT: i64 = 1_500_000;
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum WaveAmountLimitError {
+    AmountTooLow { amount: i64, min_amount: i64 },
+    AmountTooHigh { amount: i64, max_amount: i64 },
+}
+
+impl std::fmt::Display for WaveAmountLimitError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            WaveAmountLimitError::AmountTooLow { amount, min_amount } => {
+                write!(f, "Amount {} is below the Wave minimum of {}", amount, min_amount)
+            }
+            WaveAmountLimitError::AmountTooHigh { amount, max_amount } => {
+                write!(f, "Amount {} is above the Wave maximum of {}", amount, max_amount)
+            }
+        }
+    }
+}
+
+impl std::error::Error for WaveAmountLimitError {}
+
+// Out-of-range amounts are the payer's request, not an encoding problem, so they surface as unsupported
+impl From<WaveAmountLimitError> for ConnectorError {
+    fn from(error: WaveAmountLimitError) -> Self {
+        ConnectorError::NotSupported {
+            message: error.to_string(),
+            connector: "wave",
+        }
+    }
+}
+
+/// Check an amount against the configured Wave limits, falling back to the XOF defaults
+pub fn validate_wave_amount_limits(
+    amount: MinorUnit,
+    metadata: Option<&WaveConnectorMetadata>,
+) -> Result<(), WaveAmountLimitError> {
+    let amount = amount.get_amount_as_i64();
+    let min_amount = metadata
+        .and_then(|m| m.min_amount)
+        .unwrap_or(WAVE_DEFAULT_MIN_AMOUNT);
+    let max_amount = metadata
+        .and_then(|m| m.max_amount)
+        .unwrap_or(WAVE_DEFAULT_MAX_AMOUNT);
+
+    if amount < min_amount {
+        return Err(WaveAmountLimitError::AmountTooLow { amount, min_amount });
+    }
+    if amount > max_amount {
+        return Err(WaveAmountLimitError::AmountTooHigh { amount, max_amount });
+    }
+    Ok(())
+}
+
+const WAVE_RETURN_STATUS_PARAM: &str = "wave_status";
+const WAVE_RETURN_STATUS_SUCCESS: &str = "success";
+const WAVE_RETURN_STATUS_ERROR: &str = "error";
+
+/// Add the checkout outcome as a query param, leaving unparseable URLs untouched
+fn append_wave_return_status(return_url: &str, status: &str) -> String {
+    match Url::parse(return_url) {
+        Ok(mut url) => {
+            url.query_pairs_mut().append_pair(WAVE_RETURN_STATUS_PARAM, status);
+            url.to_string()
+        }
+        Err(_) => return_url.to_string(),
+    }
+}
+
+/// Wave sends the payer back to the return URL at the end of checkout, so it has to be a well-formed
+/// HTTPS URL. Plain HTTP is only accepted for localhost when `allow_local_http` is set (test mode).
+pub fn validate_wave_return_url(
+    return_url: &str,
+    allow_local_http: bool,
+) -> Result<(), error_stack::Report<ConnectorError>> {
+    validate_wave_redirect_url(return_url, "return_url", allow_local_http)
+}
+
+/// Same rules as the return URL for any URL Wave redirects the payer to, reported under `field_name`
+fn validate_wave_redirect_url(
+    redirect_url: &str,
+    field_name: &'static str,
+    allow_local_http: bool,
+) -> Result<(), error_stack::Report<ConnectorError>> {
+    let invalid = |reason: &str| {
+        error_stack::report!(ConnectorError::InvalidDataFormat { field_name })
+            .attach_printable(format!("{field_name}: {reason}"))
+    };
+    let url = Url::parse(redirect_url).map_err(|_| invalid("not a valid URL"))?;
+    let is_localhost = matches!(url.host_str(), Some("localhost" | "127.0.0.1" | "[::1]"));
+    match url.scheme() {
+        "https" if url.has_host() => Ok(()),
+        "http" if allow_local_http && is_localhost => Ok(()),
+        _ => Err(invalid("must use HTTPS")),
+    }
+}
+
 impl TryFrom<&WaveRouterData<&PaymentsAuthorizeRouterData>> for WaveCheckoutSessionRequest {
     type Error = error_stack::Report<ConnectorError>;
     fn try_from(
         item: &WaveRouterData<&PaymentsAuthorizeRouterData>,
     ) -> Result<Self, Self::Error> {
         let router_data = item.router_data;
-        let amount = item.amount.to_string();
-        let currency = router_data.request.currency.to_string();
-        
+
         let return_url = router_data.request.get_router_return_url()?;
-        
-        // Extract aggregated merchant ID from connector metadata with enhanced logic
-        let aggregated_merchant_id = extract_aggregated_merchant_id(router_data)
-            .unwrap_or(None);
+        let allow_local_http = router_data.test_mode.unwrap_or(false);
+        let request = Self::new(
+            item.amount,
+            router_data.request.currency,
+            &return_url,
+            allow_local_http,
+        )?;
+        let restrict_payer_mobile = get_wave_payer_mobile(&router_data.request.payment_method_data)?;
+
+        let metadata = parse_wave_connector_metadata(router_data.connector_meta_data.as_ref());
+        let aggregated_merchant_id = get_wave_checkout_aggregated_merchant_id(router_data)?;
         
         // Log aggregated merchant usage for monitoring
         if aggregated_merchant_id.is_some() {
@@ -173,6 +654,14 @@ impl TryFrom<&WaveRouterData<&PaymentsAuthorizeRouterData>> for WaveCheckoutSess
             );
         }
         
+        // Accounts set up to require a payer email get a clear error here instead of a Wave API rejection
+        if metadata
+            .as_ref()
+            .and_then(|m| m.require_customer_email)
+            .unwrap_or(false)
+        {
+            router_data.request.get_email()?;
+        }
         let customer = router_data.request.email.as_ref().map(|email| WaveCustomer {
             name: router_data.get_billing_address()
                 .ok()
@@ -180,18 +669,81 @@ impl TryFrom<&WaveRouterData<&PaymentsAuthorizeRouterData>> for WaveCheckoutSess
             email: Some(email.clone()),
         });
 
-        Ok(Self {
-            amount,
-            currency,
-            error_url: Some(return_url.clone()),
-            success_url: Some(return_url),
-            reference: Some(router_data.connector_request_reference_id.clone()),
-            aggregated_merchant_id, // Include aggregated merchant ID
-            customer,
-        })
+        // Configured URLs win, otherwise tag the router return URL so the outcome is visible on redirect
+        let success_url = metadata
+            .as_ref()
+            .and_then(|m| m.success_url.clone())
+            .unwrap_or_else(|| append_wave_return_status(&return_url, WAVE_RETURN_STATUS_SUCCESS));
+        let error_url = metadata
+            .as_ref()
+            .and_then(|m| m.error_url.clone())
+            .unwrap_or_else(|| append_wave_return_status(&return_url, WAVE_RETURN_STATUS_ERROR));
+
+        let platform_commission = get_wave_platform_commission(
+            item.amount,
+            metadata
+                .as_ref()
+                .filter(|m| m.business_type == Some(WaveBusinessType::Marketplace))
+                .and_then(|m| m.marketplace_commission.as_ref()),
+        )?;
+
+        let preferred_provider = get_wave_preferred_provider(metadata.as_ref())?;
+
+        Ok(request
+            .with_redirect_urls(success_url, error_url, allow_local_http)?
+            .with_reference(get_wave_request_reference(router_data))
+            .with_aggregated_merchant(aggregated_merchant_id)
+            .with_customer(customer)
+            .with_locale(get_wave_checkout_locale(
+                router_data.request.locale.as_deref(),
+                metadata.as_ref(),
+                router_data.request.currency,
+            ))
+            .with_restrict_payer_mobile(restrict_payer_mobile)
+            .with_platform_commission(platform_commission)
+            .with_preferred_provider(preferred_provider))
+    }
+}
+
+/// Language of the hosted checkout page: the payer's locale, then the configured one,
+/// falling back to French for XOF where Wave's payers are mostly French speaking
+pub fn get_wave_checkout_locale(
+    request_locale: Option<&str>,
+    metadata: Option<&WaveConnectorMetadata>,
+    currency: api_enums::Currency,
+) -> Option<String> {
+    request_locale
+        .or(metadata.and_then(|m| m.locale.as_deref()))
+        .and_then(|locale| locale.split(['-', '_']).next())
+        .map(|language| language.trim().to_lowercase())
+        .filter(|language| !language.is_empty())
+        .or_else(|| (currency == api_enums::Currency::XOF).then(|| "fr".to_string()))
+}
+
+/// Checkout reference for this attempt, kept unique across retries of the same payment
+/// Attempt ids are derived from the payment id (`{payment_id}_{n}`), so a reference that the
+/// attempt id already extends is replaced by it; any other reference gets the attempt id appended
+pub fn get_wave_checkout_reference<F, Req, Res>(router_data: &RouterData<F, Req, Res>) -> String {
+    let reference = &router_data.connector_request_reference_id;
+    if router_data.attempt_id.starts_with(reference.as_str()) {
+        router_data.attempt_id.clone()
+    } else {
+        format!("{}_{}", reference, router_data.attempt_id)
     }
 }
 
+/// Reference to send in Wave request bodies, `None` when the merchant reference is blank since Wave
+/// rejects empty references
+pub fn get_wave_request_reference<F, Req, Res>(router_data: &RouterData<F, Req, Res>) -> Option<String> {
+    non_blank(Some(router_data.connector_request_reference_id.as_str()))
+        .map(|_| get_wave_checkout_reference(router_data))
+}
+
+/// Drop empty and whitespace-only strings so they are omitted from request bodies
+pub fn non_blank(value: Option<&str>) -> Option<String> {
+    value.filter(|value| !value.trim().is_empty()).map(str::to_string)
+}
+
 #[derive(Debug, Deserialize, Serialize, Clone)]
 pub struct WaveCheckoutSessionResponse {
     pub id: String,
@@ -200,6 +752,9 @@ pub struct WaveCheckoutSessionResponse {
     pub amount: String,
     pub currency: String,
     pub reference: Option<String>,
+    /// Aggregated merchant Wave created the session under
+    #[serde(default)]
+    pub aggregated_merchant_id: Option<String>,
 }
 
 #[derive(Debug, Deserialize, Serialize, Clone, PartialEq)]
@@ -212,10 +767,19 @@ pub enum WavePaymentStatus {
     Cancelled,
 }
 
+impl WavePaymentStatus {
+    /// Whether the session is finished and the payer has nothing left to do on Wave
+    pub fn is_terminal(&self) -> bool {
+        matches!(self, Self::Completed | Self::Failed | Self::Cancelled)
+    }
+}
+
 impl From<WavePaymentStatus> for AttemptStatus {
     fn from(status: WavePaymentStatus) -> Self {
         match status {
-            WavePaymentStatus::Created | WavePaymentStatus::Pending => Self::Pending,
+            // Session exists but the payer has not been through the Wave app yet
+            WavePaymentStatus::Created => Self::AuthenticationPending,
+            WavePaymentStatus::Pending => Self::Pending,
             WavePaymentStatus::Completed => Self::Charged,
             WavePaymentStatus::Failed => Self::Failure,
             WavePaymentStatus::Cancelled => Self::Voided,
@@ -232,7 +796,37 @@ pub struct WavePaymentsCancelResponse {
 #[derive(Debug, Serialize)]
 pub struct WavePaymentsCancelRequest {
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub reason: Option<String>,
+    pub reason: Option<WaveCancellationReason>,
+}
+
+/// Cancellation reasons Wave accepts on a void
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum WaveCancellationReason {
+    RequestedByCustomer,
+    Duplicate,
+    Fraudulent,
+    Abandoned,
+    Other,
+}
+
+impl WaveCancellationReason {
+    /// Normalise a free-form cancellation reason, anything unrecognised is sent as `other`
+    pub fn from_cancellation_reason(reason: &str) -> Self {
+        let normalized = reason.trim().to_lowercase().replace(['-', ' '], "_");
+        match normalized.as_str() {
+            "requested_by_customer" | "customer_request" | "customer_cancelled" => {
+                Self::RequestedByCustomer
+            }
+            "duplicate" | "duplicate_payment" => Self::Duplicate,
+            "fraudulent" | "fraud" | "suspected_fraud" => Self::Fraudulent,
+            "abandoned" | "expired" => Self::Abandoned,
+            _ => {
+                router_env::logger::debug!("Unmapped Wave cancellation reason, sending as other");
+                Self::Other
+            }
+        }
+    }
 }
 
 impl TryFrom<&WaveRouterData<&PaymentsCancelRouterData>> for WavePaymentsCancelRequest {
@@ -241,7 +835,12 @@ impl TryFrom<&WaveRouterData<&PaymentsCancelRouterData>> for WavePaymentsCancelR
         item: &WaveRouterData<&PaymentsCancelRouterData>,
     ) -> Result<Self, Self::Error> {
         Ok(Self {
-            reason: item.router_data.request.cancellation_reason.clone(),
+            reason: item
+                .router_data
+                .request
+                .cancellation_reason
+                .as_deref()
+                .map(WaveCancellationReason::from_cancellation_reason),
         })
     }
 }
@@ -254,8 +853,110 @@ pub struct WavePaymentStatusResponse {
     pub currency: String,
     pub reference: Option<String>,
     pub launch_url: Option<String>,
+    #[serde(default)]
+    pub aggregated_merchant_id: Option<String>,
+    #[serde(default, with = "common_utils::custom_serde::iso8601::option")]
+    pub created_at: Option<PrimitiveDateTime>,
+    /// Decline code Wave reports for a failed session
+    #[serde(default)]
+    pub failure_reason: Option<String>,
+    /// Fee Wave charged on a completed session
+    #[serde(default)]
+    pub fee: Option<String>,
+    /// Amount Wave settles to the merchant once the fee is deducted
+    #[serde(default)]
+    pub net_amount: Option<String>,
+}
+
+/// Checkout sessions matching a reference search
+#[derive(Debug, Deserialize, Serialize)]
+pub struct WaveCheckoutSessionSearchResponse {
+    #[serde(default)]
+    pub result: Vec<WavePaymentStatusResponse>,
+}
+
+impl WaveCheckoutSessionSearchResponse {
+    /// Most recently created session carrying exactly this reference
+    pub fn into_latest_for_reference(self, reference: &str) -> Option<WavePaymentStatusResponse> {
+        self.result
+            .into_iter()
+            .filter(|session| session.reference.as_deref() == Some(reference))
+            .max_by_key(|session| session.created_at)
+    }
+}
+
+/// Decline codes Wave reports on failed checkout sessions
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Deserialize, strum::Display)]
+#[serde(rename_all = "snake_case")]
+#[strum(serialize_all = "snake_case")]
+pub enum WaveDeclineCode {
+    InsufficientFunds,
+    PayerCancelled,
+    PayerDeclined,
+    LimitExceeded,
+    #[serde(other)]
+    Unknown,
+}
+
+impl WaveDeclineCode {
+    pub fn from_reason(reason: &str) -> Self {
+        serde_json::from_value(serde_json::Value::String(reason.to_string()))
+            .unwrap_or(Self::Unknown)
+    }
+
+    /// Human readable message surfaced as the attempt's error message
+    pub fn message(self) -> &'static str {
+        match self {
+            Self::InsufficientFunds => "Insufficient funds in the payer's Wave wallet",
+            Self::PayerCancelled => "Payer cancelled the payment in the Wave app",
+            Self::PayerDeclined => "Payer declined the payment in the Wave app",
+            Self::LimitExceeded => "Payment exceeds the payer's Wave wallet limits",
+            Self::Unknown => "Payment failed on Wave",
+        }
+    }
+}
+
+/// Compare the amount Wave reports for a synced session with the amount we expect,
+/// logging a discrepancy instead of failing the sync
+pub fn reconcile_synced_amount(
+    item: &WaveRouterData<&PaymentsSyncRouterData>,
+    response: &WavePaymentStatusResponse,
+) -> bool {
+    let synced_amount = response.amount.trim().parse::<i64>().ok();
+    let is_consistent = synced_amount == Some(item.amount.get_amount_as_i64());
+    if !is_consistent {
+        router_env::logger::warn!(
+            "Wave session {} amount mismatch: expected={}, synced={}",
+            response.id,
+            item.amount.get_amount_as_i64(),
+            response.amount
+        );
+    }
+    is_consistent
+}
+
+/// Failure reason reported for sessions that never left `created` within the grace window
+pub const WAVE_ABANDONED_SESSION_REASON: &str = "abandoned";
+
+/// Check whether a record created at `created_at` is older than `max_age_seconds`.
+/// Used for sessions stuck in `created` and refunds stuck in `processing`; returns false when
+/// either the creation time or the limit is unknown
+pub fn has_exceeded_age(
+    created_at: Option<PrimitiveDateTime>,
+    max_age_seconds: Option<u64>,
+    now: PrimitiveDateTime,
+) -> bool {
+    match (created_at, max_age_seconds) {
+        (Some(created_at), Some(max_age_seconds)) => {
+            let max_age = time::Duration::seconds(i64::try_from(max_age_seconds).unwrap_or(i64::MAX));
+            now - created_at > max_age
+        }
+        _ => false,
+    }
 }
 
+/// No partial/full indicator is sent: Wave tells the two apart by comparing the amount with what
+/// the session captured, and the amount is bounded by the captured amount before it is sent
 #[derive(Debug, Serialize)]
 pub struct WaveRefundRequest {
     pub amount: String,
@@ -268,9 +969,21 @@ impl TryFrom<&WaveRouterData<&RefundsRouterData<Execute>>> for WaveRefundRequest
     fn try_from(
         item: &WaveRouterData<&RefundsRouterData<Execute>>,
     ) -> Result<Self, Self::Error> {
+        let amount = get_wave_amount(item.amount, "refund_amount")?;
+        // Wave auto-captures, so the payment amount is what was captured and bounds every refund
+        let captured_amount = item.router_data.request.minor_payment_amount;
+        if item.router_data.request.minor_refund_amount > captured_amount {
+            return Err(error_stack::report!(ConnectorError::RefundAmountExceedsPaymentAmount)
+                .attach_printable(format!(
+                    "Refund amount {} exceeds captured amount {}",
+                    item.router_data.request.minor_refund_amount.get_amount_as_i64(),
+                    captured_amount.get_amount_as_i64()
+                )));
+        }
+        validate_wave_currency(item.router_data.request.currency)?;
         Ok(Self {
-            amount: item.amount.to_string(),
-            reason: item.router_data.request.reason.clone(),
+            amount,
+            reason: non_blank(item.router_data.request.reason.as_deref()),
         })
     }
 }
@@ -282,6 +995,8 @@ pub struct WaveRefundResponse {
     pub amount: String,
     pub currency: String,
     pub transaction_id: Option<String>,
+    #[serde(default, with = "common_utils::custom_serde::iso8601::option")]
+    pub created_at: Option<PrimitiveDateTime>,
 }
 
 #[derive(Debug, Deserialize, Serialize, Clone, PartialEq)]
@@ -304,67 +1019,273 @@ impl From<WaveRefundStatus> for RefundStatus {
     }
 }
 
-#[derive(Debug, Deserialize)]
+#[derive(Debug, Serialize, Deserialize)]
 pub struct WaveErrorResponse {
     pub code: Option<String>,
+    /// Some Wave errors only carry a code
+    #[serde(default)]
     pub message: String,
     pub details: Option<Vec<WaveErrorDetail>>,
 }
 
+/// Wave error bodies come flat, wrapped as `{"error": {...}}` or as a bare `{"error": "..."}`
+/// depending on the endpoint
 #[derive(Debug, Deserialize)]
+#[serde(untagged)]
+enum WaveErrorEnvelope {
+    Nested { error: WaveErrorResponse },
+    Message { error: String },
+    Flat(WaveErrorResponse),
+}
+
+impl WaveErrorResponse {
+    /// Parse an error body in any envelope shape, a flat body carrying neither a code nor a
+    /// message is not a Wave error
+    pub fn parse(body: &[u8]) -> Result<Self, serde_json::Error> {
+        match serde_json::from_slice::<WaveErrorEnvelope>(body)? {
+            WaveErrorEnvelope::Nested { error } => Ok(error),
+            WaveErrorEnvelope::Message { error } => Ok(Self {
+                code: None,
+                message: error,
+                details: None,
+            }),
+            WaveErrorEnvelope::Flat(error) if error.code.is_none() && error.message.is_empty() => {
+                Err(serde::de::Error::custom(
+                    "Wave error body has neither a code nor a message",
+                ))
+            }
+            WaveErrorEnvelope::Flat(error) => Ok(error),
+        }
+    }
+
+    /// Known Wave error code for this response, `Unknown` when absent or not recognised
+    pub fn error_code(&self) -> WaveErrorCode {
+        self.code
+            .as_deref()
+            .map_or(WaveErrorCode::Unknown, WaveErrorCode::from_code)
+    }
+}
+
+/// Error codes returned by Wave that map to a specific connector error
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Deserialize)]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+pub enum WaveErrorCode {
+    AggregatedMerchantNotFound,
+    AggregatedMerchantSuspended,
+    InvalidBusinessType,
+    #[serde(other)]
+    Unknown,
+}
+
+impl WaveErrorCode {
+    /// Known code for a raw Wave error code, `Unknown` when not recognised
+    pub fn from_code(code: &str) -> Self {
+        serde_json::from_value(serde_json::Value::String(code.to_string())).unwrap_or(Self::Unknown)
+    }
+}
+
+#[derive(Debug, Serialize, Deserialize)]
 pub struct WaveErrorDetail {
     pub loc: Option<Vec<String>>,
+    #[serde(default)]
     pub msg: String,
 }
 
+/// Lifecycle status Wave reports for an aggregated merchant
+#[derive(Debug, Clone, Copy, Serialize, PartialEq, Eq, strum::Display)]
+#[serde(rename_all = "snake_case")]
+#[strum(serialize_all = "snake_case")]
+pub enum WaveAggregatedMerchantStatus {
+    Active,
+    Suspended,
+    PendingReview,
+    Unknown,
+}
+
+// Matched case-insensitively, as the status was compared before it was typed
+impl<'de> Deserialize<'de> for WaveAggregatedMerchantStatus {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        let status = String::deserialize(deserializer)?;
+        Ok(match status.to_ascii_lowercase().as_str() {
+            "active" => Self::Active,
+            "suspended" => Self::Suspended,
+            "pending_review" => Self::PendingReview,
+            _ => Self::Unknown,
+        })
+    }
+}
+
+impl WaveAggregatedMerchantStatus {
+    /// Only active merchants can take payments, everything else exists but is not usable
+    pub fn is_usable(self) -> bool {
+        self == Self::Active
+    }
+}
+
 // Wave aggregated merchant structures
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct WaveAggregatedMerchant {
     pub id: String,
     pub name: String,
     pub business_type: WaveBusinessType,
-    pub business_registration_identifier: Option<String>,
+    pub business_registration_identifier: Option<Secret<String>>,
     pub business_sector: Option<String>,
     pub website_url: Option<String>,
+    /// Optional on Wave's side, older merchants may have none
+    #[serde(default)]
     pub business_description: String,
-    pub manager_name: Option<String>,
+    pub manager_name: Option<Secret<String>>,
+    pub status: WaveAggregatedMerchantStatus,
+    #[serde(
+        default,
+        serialize_with = "common_utils::custom_serde::iso8601::option::serialize",
+        deserialize_with = "deserialize_wave_timestamp"
+    )]
+    pub created_at: Option<PrimitiveDateTime>,
+    #[serde(
+        default,
+        serialize_with = "common_utils::custom_serde::iso8601::option::serialize",
+        deserialize_with = "deserialize_wave_timestamp"
+    )]
+    pub updated_at: Option<PrimitiveDateTime>,
+}
+
+/// Connector-neutral view of an aggregated merchant for the router layer
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct AggregatedMerchantInfo {
+    pub id: String,
+    pub name: String,
+    pub business_type: String,
     pub status: String,
-    pub created_at: Option<String>,
-    pub updated_at: Option<String>,
+    pub is_active: bool,
+    pub created_at: Option<PrimitiveDateTime>,
+}
+
+/// Parse an RFC 3339 timestamp from Wave into UTC, dropping values that do not parse
+fn parse_wave_timestamp(value: Option<&str>) -> Option<PrimitiveDateTime> {
+    let value = value?;
+    match time::OffsetDateTime::parse(value, &time::format_description::well_known::Rfc3339) {
+        Ok(parsed) => {
+            let utc = parsed.to_offset(time::UtcOffset::UTC);
+            Some(PrimitiveDateTime::new(utc.date(), utc.time()))
+        }
+        Err(error) => {
+            router_env::logger::warn!("Ignoring malformed Wave timestamp {:?}: {}", value, error);
+            None
+        }
+    }
+}
+
+/// Deserialize an optional Wave timestamp, a malformed value becomes `None` rather than failing the payload
+fn deserialize_wave_timestamp<'de, D>(deserializer: D) -> Result<Option<PrimitiveDateTime>, D::Error>
+where
+    D: serde::Deserializer<'de>,
+{
+    let value = Option::<String>::deserialize(deserializer)?;
+    Ok(parse_wave_timestamp(value.as_deref()))
+}
+
+impl From<WaveAggregatedMerchant> for AggregatedMerchantInfo {
+    fn from(merchant: WaveAggregatedMerchant) -> Self {
+        Self {
+            created_at: merchant.created_at,
+            id: merchant.id,
+            name: merchant.name,
+            business_type: merchant.business_type.to_string(),
+            status: merchant.status.to_string(),
+            is_active: merchant.status.is_usable(),
+        }
+    }
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct WaveAggregatedMerchantRequest {
     pub name: String,
     pub business_type: WaveBusinessType,
-    pub business_registration_identifier: Option<String>,
+    pub business_registration_identifier: Option<Secret<String>>,
     pub business_sector: Option<String>,
     pub website_url: Option<String>,
     pub business_description: String,
-    pub manager_name: Option<String>,
+    pub manager_name: Option<Secret<String>>,
 }
 
+/// Unset fields are left unchanged, fields listed in `clear_fields` are removed on Wave
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct WaveAggregatedMerchantUpdateRequest {
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub name: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub business_type: Option<WaveBusinessType>,
-    pub business_registration_identifier: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub business_registration_identifier: Option<Secret<String>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub business_sector: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub website_url: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub business_description: Option<String>,
-    pub manager_name: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub manager_name: Option<Secret<String>>,
+    #[serde(default, skip_serializing)]
+    pub clear_fields: Vec<WaveAggregatedMerchantClearableField>,
 }
 
-// Enhanced error handling for aggregated merchant operations
-#[derive(Debug, Clone)]
-pub enum WaveAggregatedMerchantError {
-    MerchantNotFound { merchant_id: String },
+/// Optional aggregated merchant fields that can be removed, name and description are always required
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, strum::Display)]
+#[serde(rename_all = "snake_case")]
+#[strum(serialize_all = "snake_case")]
+pub enum WaveAggregatedMerchantClearableField {
+    BusinessRegistrationIdentifier,
+    BusinessSector,
+    WebsiteUrl,
+    ManagerName,
+}
+
+impl WaveAggregatedMerchantUpdateRequest {
+    fn is_set(&self, field: WaveAggregatedMerchantClearableField) -> bool {
+        match field {
+            WaveAggregatedMerchantClearableField::BusinessRegistrationIdentifier => {
+                self.business_registration_identifier.is_some()
+            }
+            WaveAggregatedMerchantClearableField::BusinessSector => self.business_sector.is_some(),
+            WaveAggregatedMerchantClearableField::WebsiteUrl => self.website_url.is_some(),
+            WaveAggregatedMerchantClearableField::ManagerName => self.manager_name.is_some(),
+        }
+    }
+
+    /// Body for Wave's update endpoint, where an explicit `null` clears a field
+    pub fn to_wave_body(&self) -> Result<serde_json::Value, error_stack::Report<ConnectorError>> {
+        let mut body = serde_json::to_value(self).map_err(|_| ConnectorError::RequestEncodingFailed)?;
+        for field in &self.clear_fields {
+            if self.is_set(*field) {
+                return Err(error_stack::report!(ConnectorError::InvalidDataFormat {
+                    field_name: "clear_fields",
+                })
+                .attach_printable(format!("{field} cannot be both updated and cleared")));
+            }
+            if let Some(object) = body.as_object_mut() {
+                object.insert(field.to_string(), serde_json::Value::Null);
+            }
+        }
+        Ok(body)
+    }
+}
+
+// Enhanced error handling for aggregated merchant operations
+#[derive(Debug, Clone)]
+pub enum WaveAggregatedMerchantError {
+    MerchantNotFound { merchant_id: String },
     CreationFailed { reason: String },
     InvalidConfiguration { details: String },
     ValidationFailed { merchant_id: String },
     AutoCreationDisabled,
     RateLimitExceeded,
     AuthenticationFailed,
+    UpdateNotApplied { merchant_id: String, fields: Vec<String> },
+    DeleteConflict { merchant_id: String, reason: String },
 }
 
 impl std::fmt::Display for WaveAggregatedMerchantError {
@@ -391,6 +1312,12 @@ impl std::fmt::Display for WaveAggregatedMerchantError {
             WaveAggregatedMerchantError::AuthenticationFailed => {
                 write!(f, "Authentication failed for aggregated merchant operations")
             }
+            WaveAggregatedMerchantError::UpdateNotApplied { merchant_id, fields } => {
+                write!(f, "Aggregated merchant {} ignored update of: {}", merchant_id, fields.join(", "))
+            }
+            WaveAggregatedMerchantError::DeleteConflict { merchant_id, reason } => {
+                write!(f, "Aggregated merchant {} cannot be deleted: {}", merchant_id, reason)
+            }
         }
     }
 }
@@ -418,27 +1345,62 @@ impl From<WaveAggregatedMerchantError> for ConnectorError {
             WaveAggregatedMerchantError::RateLimitExceeded => {
                 ConnectorError::ProcessingStepFailed(Some(error.to_string().into()))
             }
-            WaveAggregatedMerchantError::AuthenticationFailed => {
-                ConnectorError::FailedToObtainAuthType
+            WaveAggregatedMerchantError::UpdateNotApplied { .. } => {
+                ConnectorError::ProcessingStepFailed(Some(error.to_string().into()))
+            }
+            WaveAggregatedMerchantError::DeleteConflict { .. } => {
+                ConnectorError::ProcessingStepFailed(Some(error.to_string().into()))
             }
+            WaveAggregatedMerchantError::AuthenticationFailed => ConnectorError::AuthenticationFailed,
         }
     }
 }
 
+/// Names of the requested update fields that the merchant does not reflect
+pub fn find_unapplied_update_fields(
+    request: &WaveAggregatedMerchantUpdateRequest,
+    merchant: &WaveAggregatedMerchant,
+) -> Vec<String> {
+    let checks = [
+        ("name", request.name.as_ref().map(|name| name == &merchant.name)),
+        ("business_type", request.business_type.as_ref().map(|business_type| business_type == &merchant.business_type)),
+        ("business_registration_identifier", request.business_registration_identifier.as_ref().map(|id| Some(id) == merchant.business_registration_identifier.as_ref())),
+        ("business_sector", request.business_sector.as_ref().map(|sector| Some(sector) == merchant.business_sector.as_ref())),
+        ("website_url", request.website_url.as_ref().map(|url| Some(url) == merchant.website_url.as_ref())),
+        ("business_description", request.business_description.as_ref().map(|description| description == &merchant.business_description)),
+        ("manager_name", request.manager_name.as_ref().map(|manager| Some(manager) == merchant.manager_name.as_ref())),
+    ];
+    let uncleared = request.clear_fields.iter().filter(|field| match field {
+        WaveAggregatedMerchantClearableField::BusinessRegistrationIdentifier => {
+            merchant.business_registration_identifier.is_some()
+        }
+        WaveAggregatedMerchantClearableField::BusinessSector => merchant.business_sector.is_some(),
+        WaveAggregatedMerchantClearableField::WebsiteUrl => merchant.website_url.is_some(),
+        WaveAggregatedMerchantClearableField::ManagerName => merchant.manager_name.is_some(),
+    });
+
+    checks
+        .into_iter()
+        .filter(|(_, applied)| *applied == Some(false))
+        .map(|(field, _)| field.to_string())
+        .chain(uncleared.map(ToString::to_string))
+        .collect()
+}
+
 /// Parse Wave API error response and convert to appropriate error
 pub fn parse_wave_api_error(status: u16, body: &str) -> ConnectorError {
     // Try to parse as Wave error response
-    if let Ok(error_response) = serde_json::from_str::<WaveErrorResponse>(body) {
-        let error_code = error_response.code.unwrap_or_default();
+    if let Ok(error_response) = WaveErrorResponse::parse(body.as_bytes()) {
+        let error_code = error_response.error_code();
         let error_message = error_response.message;
         
-        match (status, error_code.as_str()) {
-            (404, "AGGREGATED_MERCHANT_NOT_FOUND") => {
+        match (status, error_code) {
+            (404, WaveErrorCode::AggregatedMerchantNotFound) => {
                 WaveAggregatedMerchantError::MerchantNotFound {
                     merchant_id: "unknown".to_string(),
                 }.into()
             }
-            (400, "INVALID_BUSINESS_TYPE") => {
+            (400, WaveErrorCode::InvalidBusinessType) => {
                 WaveAggregatedMerchantError::InvalidConfiguration {
                     details: error_message,
                 }.into()
@@ -463,8 +1425,150 @@ pub fn parse_wave_api_error(status: u16, body: &str) -> ConnectorError {
     }
 }
 
+/// Minimal view of a Wave webhook body, used to route on the event `type`
+#[derive(Debug, Deserialize)]
+pub struct WaveWebhookEventTypeBody {
+    #[serde(rename = "type")]
+    pub event_type: WaveWebhookEvent,
+}
+
+/// Wave webhook body with the object the event is about
+#[derive(Debug, Deserialize)]
+pub struct WaveWebhookBody {
+    #[serde(rename = "type")]
+    pub event_type: WaveWebhookEvent,
+    pub data: WaveWebhookData,
+}
+
+/// Object carried in a Wave webhook: a checkout session for checkout events, a refund for refund events
+#[derive(Debug, Serialize, Deserialize)]
+pub struct WaveWebhookData {
+    pub id: String,
+    /// Original transaction of a refund, only sent on refund events
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub transaction_id: Option<String>,
+}
+
+/// Parsed `Wave-Signature` header: `t=<timestamp>,v1=<hex hmac>[,v1=<hex hmac>...]`.
+/// Several `v1` entries are sent while a webhook secret is being rotated.
+#[derive(Debug, PartialEq)]
+pub struct WaveWebhookSignature {
+    pub timestamp: String,
+    pub signatures: Vec<Vec<u8>>,
+}
+
+impl WaveWebhookSignature {
+    pub fn parse(header: &str) -> Option<Self> {
+        let mut timestamp = None;
+        let mut signatures = Vec::new();
+        for part in header.split(',') {
+            match part.trim().split_once('=') {
+                Some(("t", value)) => timestamp = Some(value.to_string()),
+                Some(("v1", value)) => signatures.push(hex::decode(value).ok()?),
+                _ => {}
+            }
+        }
+        let timestamp = timestamp.filter(|timestamp| !timestamp.is_empty())?;
+        (!signatures.is_empty()).then_some(Self {
+            timestamp,
+            signatures,
+        })
+    }
+
+    /// Wave signs the timestamp immediately followed by the raw request body
+    pub fn signed_message(&self, body: &[u8]) -> Vec<u8> {
+        [self.timestamp.as_bytes(), body].concat()
+    }
+
+    /// Replayed deliveries carry a valid signature, so the signed timestamp must be
+    /// within `tolerance_seconds` of `now` in either direction
+    pub fn is_within_tolerance(&self, now: i64, tolerance_seconds: i64) -> bool {
+        self.timestamp
+            .parse::<i64>()
+            .is_ok_and(|timestamp| (now - timestamp).abs() <= tolerance_seconds)
+    }
+}
+
+/// Wave webhook event types we act on
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum WaveWebhookEvent {
+    #[serde(rename = "checkout.session.completed")]
+    CheckoutCompleted,
+    #[serde(rename = "checkout.session.payment_failed")]
+    CheckoutFailed,
+    #[serde(rename = "checkout.session.expired")]
+    CheckoutExpired,
+    #[serde(rename = "refund.completed")]
+    RefundCompleted,
+    #[serde(rename = "refund.failed")]
+    RefundFailed,
+    #[serde(other)]
+    Unknown,
+}
+
+impl WaveWebhookEvent {
+    /// Router event for this Wave event
+    /// Unknown types are acknowledged as not supported so that Wave does not keep retrying them
+    pub fn incoming_webhook_event(self) -> IncomingWebhookEvent {
+        match self {
+            Self::CheckoutCompleted => IncomingWebhookEvent::PaymentIntentSuccess,
+            Self::CheckoutFailed => IncomingWebhookEvent::PaymentIntentFailure,
+            Self::CheckoutExpired => IncomingWebhookEvent::PaymentIntentExpired,
+            Self::RefundCompleted => IncomingWebhookEvent::RefundSuccess,
+            Self::RefundFailed => IncomingWebhookEvent::RefundFailure,
+            Self::Unknown => {
+                router_env::logger::debug!("Unsupported Wave webhook event type");
+                IncomingWebhookEvent::EventNotSupported
+            }
+        }
+    }
+
+    /// Reference to the payment or refund the event is about, given the id of the object in the webhook `data`
+    pub fn object_reference(self, object_id: &str) -> Option<ObjectReferenceId> {
+        match self {
+            Self::CheckoutCompleted | Self::CheckoutFailed | Self::CheckoutExpired => {
+                Some(ObjectReferenceId::PaymentId(
+                    PaymentIdType::ConnectorTransactionId(object_id.to_string()),
+                ))
+            }
+            Self::RefundCompleted | Self::RefundFailed => Some(ObjectReferenceId::RefundId(
+                RefundIdType::ConnectorRefundId(object_id.to_string()),
+            )),
+            Self::Unknown => None,
+        }
+    }
+
+    /// Router event and object reference for this event
+    pub fn dispatch(self, object_id: &str) -> (IncomingWebhookEvent, Option<ObjectReferenceId>) {
+        (self.incoming_webhook_event(), self.object_reference(object_id))
+    }
+}
+
+/// Callback registration for a merchant's Wave account
+#[derive(Debug, Clone, Serialize)]
+pub struct WaveWebhookRegistrationRequest {
+    pub url: String,
+    pub events: Vec<WaveWebhookEvent>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct WaveWebhookEndpoint {
+    pub id: String,
+    pub url: String,
+    #[serde(default)]
+    pub events: Vec<WaveWebhookEvent>,
+}
+
+// Empty collections may be omitted from list responses
+#[derive(Debug, Deserialize)]
+pub struct WaveWebhookEndpointListResponse {
+    #[serde(default)]
+    pub webhooks: Vec<WaveWebhookEndpoint>,
+}
+
 #[derive(Debug, Deserialize)]
 pub struct WaveAggregatedMerchantListResponse {
+    #[serde(default)]
     pub aggregated_merchants: Vec<WaveAggregatedMerchant>,
     pub total_count: Option<i32>,
     pub next_cursor: Option<String>,
@@ -477,12 +1581,26 @@ pub struct WaveConnectorMetadata {
     pub auto_create_aggregated_merchant: Option<bool>,
     pub business_type: Option<WaveBusinessType>,
     pub business_description: Option<String>,
-    pub manager_name: Option<String>,
-    pub business_registration_identifier: Option<String>,
+    pub manager_name: Option<Secret<String>>,
+    pub business_registration_identifier: Option<Secret<String>>,
     pub business_sector: Option<String>,
     pub website_url: Option<String>,
     pub cache_enabled: Option<bool>,
     pub cache_ttl_seconds: Option<u64>,
+    pub created_status_grace_seconds: Option<u64>,
+    pub min_amount: Option<i64>,
+    pub max_amount: Option<i64>,
+    pub success_url: Option<String>,
+    pub error_url: Option<String>,
+    pub locale: Option<String>,
+    pub refund_pending_max_age_seconds: Option<u64>,
+    pub webhook_id: Option<String>,
+    pub require_customer_email: Option<bool>,
+    pub business_description_template: Option<String>,
+    pub marketplace_commission: Option<WaveMarketplaceCommission>,
+    pub include_request_body_in_events: Option<bool>,
+    pub preferred_provider: Option<String>,
+    pub on_resolution_failure: Option<WaveResolutionFailurePolicy>,
 }
 
 impl Default for WaveConnectorMetadata {
@@ -499,6 +1617,20 @@ impl Default for WaveConnectorMetadata {
             website_url: None,
             cache_enabled: Some(true),
             cache_ttl_seconds: Some(3600), // 1 hour default
+            created_status_grace_seconds: None,
+            min_amount: None,
+            max_amount: None,
+            success_url: None,
+            error_url: None,
+            locale: None,
+            refund_pending_max_age_seconds: None,
+            webhook_id: None,
+            require_customer_email: None,
+            business_description_template: None,
+            marketplace_commission: None,
+            include_request_body_in_events: None,
+            preferred_provider: None,
+            on_resolution_failure: None,
         }
     }
 }
@@ -508,8 +1640,71 @@ impl Default for WaveConnectorMetadata {
 pub fn extract_aggregated_merchant_id(
     router_data: &PaymentsAuthorizeRouterData,
 ) -> Result<Option<String>, error_stack::Report<ConnectorError>> {
-    // First try to get from connector account metadata
-    if let Some(connector_meta) = &router_data.connector_meta_data {
+    get_aggregated_merchant_id(
+        router_data.request.metadata.as_ref(),
+        router_data.connector_meta_data.as_ref(),
+    )
+}
+
+/// Aggregated merchant PreProcessing looks up on Wave before authorize, `None` when aggregated
+/// merchants are disabled or the payment has none
+pub fn get_aggregated_merchant_to_confirm(
+    router_data: &PaymentsPreProcessingRouterData,
+) -> Result<Option<String>, error_stack::Report<ConnectorError>> {
+    let auth = WaveAuthType::try_from(&router_data.connector_auth_type)?;
+    if !auth.aggregated_merchants_enabled {
+        return Ok(None);
+    }
+    get_aggregated_merchant_id(
+        router_data.request.metadata.as_ref().map(PeekInterface::peek),
+        router_data.connector_meta_data.as_ref(),
+    )
+}
+
+/// Aggregated merchant the checkout session is created under. With aggregated merchants enabled,
+/// PreProcessing stores the id in `preprocessing_id` once Wave reported the merchant active; a
+/// merchant it could not confirm fails the payment or is dropped depending on `on_resolution_failure`
+pub fn get_wave_checkout_aggregated_merchant_id(
+    router_data: &PaymentsAuthorizeRouterData,
+) -> Result<Option<String>, error_stack::Report<ConnectorError>> {
+    let auth = WaveAuthType::try_from(&router_data.connector_auth_type)?;
+    match extract_aggregated_merchant_id(router_data)? {
+        Some(aggregated_merchant_id)
+            if auth.aggregated_merchants_enabled
+                && router_data.preprocessing_id.as_ref() != Some(&aggregated_merchant_id) =>
+        {
+            let metadata = parse_wave_connector_metadata(router_data.connector_meta_data.as_ref());
+            match get_resolution_failure_policy(metadata.as_ref()) {
+                WaveResolutionFailurePolicy::FailPayment => {
+                    Err(error_stack::report!(ConnectorError::ProcessingStepFailed(Some(
+                        format!("Aggregated merchant {aggregated_merchant_id} could not be confirmed active")
+                            .into()
+                    ))))
+                }
+                WaveResolutionFailurePolicy::Degrade => {
+                    router_env::logger::warn!(
+                        "Aggregated merchant {} could not be confirmed active, continuing without it",
+                        aggregated_merchant_id
+                    );
+                    Ok(None)
+                }
+            }
+        }
+        aggregated_merchant_id => Ok(aggregated_merchant_id),
+    }
+}
+
+fn get_aggregated_merchant_id(
+    payment_metadata: Option<&serde_json::Value>,
+    connector_meta_data: Option<&SecretSerdeValue>,
+) -> Result<Option<String>, error_stack::Report<ConnectorError>> {
+    // A per-payment override wins over the connector account configuration
+    if let Some(override_id) = extract_payment_aggregated_merchant_override(payment_metadata)? {
+        return Ok(Some(override_id));
+    }
+
+    // Then try to get from connector account metadata
+    if let Some(connector_meta) = connector_meta_data {
         if let Ok(wave_metadata) = serde_json::from_value::<WaveConnectorMetadata>(connector_meta.peek().clone()) {
             if let Some(aggregated_merchant_id) = wave_metadata.aggregated_merchant_id {
                 return Ok(Some(aggregated_merchant_id));
@@ -523,53 +1718,131 @@ pub fn extract_aggregated_merchant_id(
     Ok(None)
 }
 
+/// Aggregated merchant id set for this payment only under the reserved
+/// `wave_aggregated_merchant_id` key of the payment metadata. Payment metadata without that key,
+/// whatever its shape, is ignored, but a malformed override fails the payment instead of quietly
+/// sending it to the account's aggregated merchant
+fn extract_payment_aggregated_merchant_override(
+    payment_metadata: Option<&serde_json::Value>,
+) -> Result<Option<String>, error_stack::Report<ConnectorError>> {
+    let Some(value) = payment_metadata
+        .and_then(|metadata| metadata.get(WAVE_AGGREGATED_MERCHANT_OVERRIDE_KEY))
+        .filter(|value| !value.is_null())
+    else {
+        return Ok(None);
+    };
+    let invalid_override = || {
+        error_stack::report!(ConnectorError::InvalidDataFormat {
+            field_name: "metadata.wave_aggregated_merchant_id",
+        })
+        .attach_printable("per-payment aggregated merchant id must be a string of the form am-xxxxxxxxx")
+    };
+    let override_id = value.as_str().ok_or_else(invalid_override)?;
+    WaveAggregatedMerchantId::new(override_id).map_err(|_| invalid_override())?;
+    router_env::logger::info!(
+        "Using per-payment aggregated merchant override: {}",
+        override_id
+    );
+    Ok(Some(override_id.to_string()))
+}
+
 /// Extract Wave connector metadata from router data
 pub fn extract_wave_connector_metadata(
     router_data: &PaymentsAuthorizeRouterData,
 ) -> Result<Option<WaveConnectorMetadata>, error_stack::Report<ConnectorError>> {
-    if let Some(connector_meta) = &router_data.connector_meta_data {
-        match serde_json::from_value::<WaveConnectorMetadata>(connector_meta.peek().clone()) {
-            Ok(metadata) => Ok(Some(metadata)),
-            Err(_) => Ok(None), // Invalid metadata format, return None
+    Ok(parse_wave_connector_metadata(router_data.connector_meta_data.as_ref()))
+}
+
+/// Parse Wave connector metadata from the raw connector account metadata of any flow
+pub fn parse_wave_connector_metadata(
+    connector_meta_data: Option<&SecretSerdeValue>,
+) -> Option<WaveConnectorMetadata> {
+    connector_meta_data.and_then(|connector_meta| {
+        let value = connector_meta.peek();
+        if !value.is_object() {
+            // A wrong shape is a configuration mistake rather than an account without Wave fields
+            if let Some(shape) = non_object_metadata_shape(value) {
+                router_env::logger::warn!(
+                    "Wave connector metadata must be a JSON object but is {}, ignoring it",
+                    shape
+                );
+            }
+            return None;
         }
-    } else {
-        Ok(None)
+        serde_json::from_value::<WaveConnectorMetadata>(value.clone())
+            .map_err(|error| {
+                router_env::logger::warn!("Wave connector metadata has invalid Wave fields: {}", error);
+            })
+            .ok()
+    })
+}
+
+/// JSON type name of metadata that is not an object, `None` for objects and for unset (`null`) metadata
+pub fn non_object_metadata_shape(value: &serde_json::Value) -> Option<&'static str> {
+    match value {
+        serde_json::Value::Object(_) | serde_json::Value::Null => None,
+        serde_json::Value::Array(_) => Some("an array"),
+        serde_json::Value::String(_) => Some("a string"),
+        serde_json::Value::Number(_) => Some("a number"),
+        serde_json::Value::Bool(_) => Some("a boolean"),
+    }
+}
+
+/// Connector metadata with the registered Wave `webhook_id` set, keeping every other configured field
+pub fn with_webhook_id(connector_meta_data: Option<&SecretSerdeValue>, webhook_id: &str) -> SecretSerdeValue {
+    with_metadata_field(connector_meta_data, "webhook_id", webhook_id)
+}
+
+fn with_metadata_field(
+    connector_meta_data: Option<&SecretSerdeValue>,
+    field: &str,
+    value: &str,
+) -> SecretSerdeValue {
+    let mut metadata = connector_meta_data
+        .map(|connector_meta| connector_meta.peek().clone())
+        .filter(serde_json::Value::is_object)
+        .unwrap_or_else(|| serde_json::json!({}));
+    if let Some(fields) = metadata.as_object_mut() {
+        fields.insert(field.to_string(), serde_json::Value::String(value.to_string()));
     }
+    Secret::new(metadata)
 }
 
-/// Build aggregated merchant request from business profile information with enhanced metadata support
+/// Build aggregated merchant request from business profile information with enhanced metadata support.
+/// `tax_registration_id` fills `business_registration_identifier` when the metadata does not set one.
 pub fn build_aggregated_merchant_request_from_profile(
     profile_name: &str,
     metadata: Option<&WaveConnectorMetadata>,
+    tax_registration_id: Option<Secret<String>>,
 ) -> Result<WaveAggregatedMerchantRequest, WaveAggregatedMerchantError> {
-    let default_description = format!("Payment processing for {}", profile_name);
+    let default_description = render_business_description_template(profile_name, metadata);
     
     // Validate metadata if provided
     if let Some(meta) = metadata {
         validate_enhanced_wave_connector_metadata(meta, profile_name)?;
     }
     
-    let request = WaveAggregatedMerchantRequest {
-        name: profile_name.to_string(),
-        business_type: metadata
-            .and_then(|m| m.business_type.clone())
-            .unwrap_or_default(),
-        business_registration_identifier: metadata
-            .and_then(|m| m.business_registration_identifier.clone()),
-        business_sector: metadata
-            .and_then(|m| m.business_sector.clone()),
-        website_url: metadata
-            .and_then(|m| m.website_url.clone()),
-        business_description: metadata
-            .and_then(|m| m.business_description.clone())
-            .unwrap_or(default_description),
-        manager_name: metadata.and_then(|m| m.manager_name.clone()),
-    };
-    
-    // Validate the final request
-    validate_wave_aggregated_merchant_request(&request)?;
-    
-    Ok(request)
+    WaveAggregatedMerchantRequestBuilder::new()
+        .name(profile_name)
+        .business_type(
+            metadata
+                .and_then(|m| m.business_type.clone())
+                .unwrap_or_default(),
+        )
+        .business_registration_identifier(
+            metadata
+                .and_then(|m| m.business_registration_identifier.clone())
+                .or(tax_registration_id),
+        )
+        .business_sector(metadata.and_then(|m| m.business_sector.clone()))
+        .website_url(metadata.and_then(|m| m.website_url.clone()))
+        .business_description(
+            metadata
+                .and_then(|m| m.business_description.clone())
+                .unwrap_or(default_description),
+        )
+        .manager_name(metadata.and_then(|m| m.manager_name.clone()))
+        .build()
 }
 
 /// Validate Wave connector metadata for aggregated merchants
@@ -594,7 +1867,7 @@ pub fn validate_wave_connector_metadata(
     
     // Validate business description length
     if let Some(ref description) = metadata.business_description {
-        if description.len() > 500 {
+        if description.chars().count() > 500 {
             return Err(WaveAggregatedMerchantError::InvalidConfiguration {
                 details: "Business description cannot exceed 500 characters".to_string(),
             });
@@ -609,13 +1882,13 @@ pub fn validate_wave_connector_metadata(
     
     // Validate manager name length
     if let Some(ref manager_name) = metadata.manager_name {
-        if manager_name.len() > 100 {
+        if manager_name.peek().chars().count() > 100 {
             return Err(WaveAggregatedMerchantError::InvalidConfiguration {
                 details: "Manager name cannot exceed 100 characters".to_string(),
             });
         }
         
-        if manager_name.trim().is_empty() {
+        if manager_name.peek().trim().is_empty() {
             return Err(WaveAggregatedMerchantError::InvalidConfiguration {
                 details: "Manager name cannot be empty or only whitespace".to_string(),
             });
@@ -624,7 +1897,7 @@ pub fn validate_wave_connector_metadata(
     
     // Validate website URL format if provided
     if let Some(ref url) = metadata.website_url {
-        if url.len() > 2083 {
+        if url.chars().count() > 2083 {
             return Err(WaveAggregatedMerchantError::InvalidConfiguration {
                 details: "Website URL cannot exceed 2083 characters".to_string(),
             });
@@ -640,7 +1913,7 @@ pub fn validate_wave_connector_metadata(
     
     // Validate business registration identifier format if provided
     if let Some(ref identifier) = metadata.business_registration_identifier {
-        if identifier.len() > 50 {
+        if identifier.peek().chars().count() > 50 {
             return Err(WaveAggregatedMerchantError::InvalidConfiguration {
                 details: "Business registration identifier cannot exceed 50 characters".to_string(),
             });
@@ -649,7 +1922,7 @@ pub fn validate_wave_connector_metadata(
     
     // Validate business sector if provided
     if let Some(ref sector) = metadata.business_sector {
-        if sector.len() > 100 {
+        if sector.chars().count() > 100 {
             return Err(WaveAggregatedMerchantError::InvalidConfiguration {
                 details: "Business sector cannot exceed 100 characters".to_string(),
             });
@@ -671,6 +1944,20 @@ pub fn validate_wave_connector_metadata(
         }
     }
     
+    // Validate amount limits if provided
+    if let (Some(min_amount), Some(max_amount)) = (metadata.min_amount, metadata.max_amount) {
+        if min_amount > max_amount {
+            return Err(WaveAggregatedMerchantError::InvalidConfiguration {
+                details: "Minimum amount cannot exceed maximum amount".to_string(),
+            });
+        }
+    }
+    if metadata.min_amount.is_some_and(|amount| amount <= 0) || metadata.max_amount.is_some_and(|amount| amount <= 0) {
+        return Err(WaveAggregatedMerchantError::InvalidConfiguration {
+            details: "Amount limits must be greater than zero".to_string(),
+        });
+    }
+
     // Validate cache TTL if provided
     if let Some(cache_ttl) = metadata.cache_ttl_seconds {
         if cache_ttl < 60 || cache_ttl > 86400 {
@@ -701,12 +1988,22 @@ pub fn validate_enhanced_wave_connector_metadata(
         }
         
         // Validate profile name for auto-creation
-        if profile_name.is_empty() || profile_name.len() > 255 {
+        if profile_name.is_empty() || profile_name.chars().count() > 255 {
             return Err(WaveAggregatedMerchantError::InvalidConfiguration {
                 details: "Profile name must be between 1 and 255 characters for auto-creation".to_string(),
             });
         }
     }
+
+    // The template is only checked once the profile name is known, that is when it can overflow
+    if metadata.business_description_template.is_some() {
+        let description = render_business_description_template(profile_name, Some(metadata));
+        if description.trim().is_empty() || description.chars().count() > 500 {
+            return Err(WaveAggregatedMerchantError::InvalidConfiguration {
+                details: "Business description template must render to between 1 and 500 characters".to_string(),
+            });
+        }
+    }
     
     Ok(())
 }
@@ -723,6 +2020,23 @@ pub fn is_auto_creation_ready(metadata: &Option<WaveConnectorMetadata>) -> bool
     }
 }
 
+/// Payment metadata key a merchant can set to route a single payment to another aggregated merchant
+pub const WAVE_AGGREGATED_MERCHANT_OVERRIDE_KEY: &str = "wave_aggregated_merchant_id";
+
+/// Description template used when the metadata sets neither a description nor a template
+pub const WAVE_DEFAULT_BUSINESS_DESCRIPTION_TEMPLATE: &str = "Payment processing for {profile}";
+
+/// Render the configured (or default) description template, substituting `{profile}`
+pub fn render_business_description_template(
+    profile_name: &str,
+    metadata: Option<&WaveConnectorMetadata>,
+) -> String {
+    metadata
+        .and_then(|m| m.business_description_template.as_deref())
+        .unwrap_or(WAVE_DEFAULT_BUSINESS_DESCRIPTION_TEMPLATE)
+        .replace("{profile}", profile_name)
+}
+
 /// Get effective business description for aggregated merchant creation
 pub fn get_effective_business_description(
     profile_name: &str,
@@ -730,7 +2044,27 @@ pub fn get_effective_business_description(
 ) -> String {
     metadata
         .and_then(|m| m.business_description.clone())
-        .unwrap_or_else(|| format!("Payment processing for {}", profile_name))
+        .unwrap_or_else(|| render_business_description_template(profile_name, metadata))
+}
+
+/// What a payment does when its aggregated merchant cannot be resolved
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum WaveResolutionFailurePolicy {
+    /// Fail the payment, for merchants that must never settle outside their aggregated merchant
+    FailPayment,
+    /// Continue without an aggregated merchant
+    #[default]
+    Degrade,
+}
+
+/// Resolution failure policy configured on the connector, degrading by default
+pub fn get_resolution_failure_policy(
+    metadata: Option<&WaveConnectorMetadata>,
+) -> WaveResolutionFailurePolicy {
+    metadata
+        .and_then(|m| m.on_resolution_failure)
+        .unwrap_or_default()
 }
 
 /// Check if caching is enabled for aggregated merchant data
@@ -749,12 +2083,33 @@ pub fn get_cache_ttl_seconds(metadata: &Option<WaveConnectorMetadata>) -> u64 {
         .unwrap_or(3600) // Default to 1 hour
 }
 
+/// Short hex digest used to scope cache keys without embedding the raw value
+fn cache_key_digest(value: &str) -> String {
+    let digest = sha2::Sha256::digest(value.as_bytes());
+    hex::encode(&digest[..8])
+}
+
+/// Cache key for aggregated merchant data.
+/// Sandbox and production share merchant ids, so the key is scoped by the base URL as well as the API key.
+pub fn get_aggregated_merchant_cache_key(
+    api_key: &Secret<String>,
+    base_url: &str,
+    merchant_id: &str,
+) -> String {
+    format!(
+        "wave_aggregated_merchant_{}_{}_{}",
+        cache_key_digest(base_url.trim_end_matches('/')),
+        cache_key_digest(api_key.peek()),
+        merchant_id
+    )
+}
+
 /// Validate Wave aggregated merchant request before sending
 pub fn validate_wave_aggregated_merchant_request(
     request: &WaveAggregatedMerchantRequest,
 ) -> Result<(), WaveAggregatedMerchantError> {
     // Validate merchant name
-    if request.name.is_empty() || request.name.len() > 255 {
+    if request.name.is_empty() || request.name.chars().count() > 255 {
         return Err(WaveAggregatedMerchantError::InvalidConfiguration {
             details: "Merchant name must be between 1 and 255 characters".to_string(),
         });
@@ -767,7 +2122,7 @@ pub fn validate_wave_aggregated_merchant_request(
         });
     }
     
-    if request.business_description.len() > 500 {
+    if request.business_description.chars().count() > 500 {
         return Err(WaveAggregatedMerchantError::InvalidConfiguration {
             details: "Business description cannot exceed 500 characters".to_string(),
         });
@@ -775,7 +2130,7 @@ pub fn validate_wave_aggregated_merchant_request(
     
     // Validate website URL format if provided
     if let Some(ref url) = request.website_url {
-        if url.len() > 2083 {
+        if url.chars().count() > 2083 {
             return Err(WaveAggregatedMerchantError::InvalidConfiguration {
                 details: "Website URL cannot exceed 2083 characters".to_string(),
             });
@@ -791,7 +2146,7 @@ pub fn validate_wave_aggregated_merchant_request(
     
     // Validate business registration identifier format if provided
     if let Some(ref identifier) = request.business_registration_identifier {
-        if identifier.len() > 50 {
+        if identifier.peek().chars().count() > 50 {
             return Err(WaveAggregatedMerchantError::InvalidConfiguration {
                 details: "Business registration identifier cannot exceed 50 characters".to_string(),
             });
@@ -800,7 +2155,7 @@ pub fn validate_wave_aggregated_merchant_request(
     
     // Validate business sector if provided
     if let Some(ref sector) = request.business_sector {
-        if sector.len() > 100 {
+        if sector.chars().count() > 100 {
             return Err(WaveAggregatedMerchantError::InvalidConfiguration {
                 details: "Business sector cannot exceed 100 characters".to_string(),
             });
@@ -809,7 +2164,7 @@ pub fn validate_wave_aggregated_merchant_request(
     
     // Validate manager name if provided
     if let Some(ref manager_name) = request.manager_name {
-        if manager_name.len() > 100 {
+        if manager_name.peek().chars().count() > 100 {
             return Err(WaveAggregatedMerchantError::InvalidConfiguration {
                 details: "Manager name cannot exceed 100 characters".to_string(),
             });
@@ -820,8 +2175,193 @@ pub fn validate_wave_aggregated_merchant_request(
 }
 
 
+/// Builder for `WaveAggregatedMerchantRequest` that only hands out validated requests
+#[derive(Debug, Default)]
+pub struct WaveAggregatedMerchantRequestBuilder {
+    name: Option<String>,
+    business_type: WaveBusinessType,
+    business_registration_identifier: Option<Secret<String>>,
+    business_sector: Option<String>,
+    website_url: Option<String>,
+    business_description: Option<String>,
+    manager_name: Option<Secret<String>>,
+}
+
+impl WaveAggregatedMerchantRequestBuilder {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn name(mut self, name: impl Into<String>) -> Self {
+        self.name = Some(name.into());
+        self
+    }
+
+    pub fn business_type(mut self, business_type: WaveBusinessType) -> Self {
+        self.business_type = business_type;
+        self
+    }
+
+    pub fn business_registration_identifier(mut self, identifier: Option<Secret<String>>) -> Self {
+        self.business_registration_identifier = identifier;
+        self
+    }
+
+    pub fn business_sector(mut self, sector: Option<String>) -> Self {
+        self.business_sector = sector;
+        self
+    }
+
+    pub fn website_url(mut self, website_url: Option<String>) -> Self {
+        self.website_url = website_url;
+        self
+    }
+
+    pub fn business_description(mut self, description: impl Into<String>) -> Self {
+        self.business_description = Some(description.into());
+        self
+    }
+
+    pub fn manager_name(mut self, manager_name: Option<Secret<String>>) -> Self {
+        self.manager_name = manager_name;
+        self
+    }
+
+    pub fn build(self) -> Result<WaveAggregatedMerchantRequest, WaveAggregatedMerchantError> {
+        let name = self.name.ok_or(WaveAggregatedMerchantError::InvalidConfiguration {
+            details: "Merchant name is required".to_string(),
+        })?;
+
+        let business_description = self
+            .business_description
+            .filter(|description| !description.trim().is_empty())
+            .ok_or(WaveAggregatedMerchantError::InvalidConfiguration {
+                details: "Business description is required".to_string(),
+            })?;
+
+        // The prefix check in the request validator accepts strings that are not URLs at all
+        if let Some(ref url) = self.website_url {
+            Url::parse(url).map_err(|_| WaveAggregatedMerchantError::InvalidConfiguration {
+                details: "Website URL is not a valid URL".to_string(),
+            })?;
+        }
+
+        let request = WaveAggregatedMerchantRequest {
+            name,
+            business_type: self.business_type,
+            business_registration_identifier: self.business_registration_identifier,
+            business_sector: self.business_sector,
+            website_url: self.website_url,
+            business_description,
+            manager_name: self.manager_name,
+        };
+
+        validate_wave_aggregated_merchant_request(&request)?;
+
+        Ok(request)
+    }
+}
 
 // Response transformations
+/// Reference Wave echoed on a checkout session that differs from the one we sent
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct WaveReferenceMismatch {
+    pub expected: String,
+    pub received: Option<String>,
+}
+
+/// Compare the echoed checkout reference with the one we sent, without failing the payment
+pub fn check_reference_echo(
+    expected: &str,
+    received: Option<&str>,
+) -> Option<WaveReferenceMismatch> {
+    if received == Some(expected) {
+        return None;
+    }
+    router_env::logger::warn!(
+        "Wave checkout reference mismatch: expected={}, received={:?}",
+        expected,
+        received
+    );
+    Some(WaveReferenceMismatch {
+        expected: expected.to_string(),
+        received: received.map(str::to_string),
+    })
+}
+
+/// Amount or currency of a created checkout session that differs from what we requested
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct WaveAmountMismatch {
+    pub expected_amount: i64,
+    pub received_amount: String,
+    pub expected_currency: String,
+    pub received_currency: String,
+}
+
+/// Compare the amount and currency of a created session with the ones we sent, without failing the payment
+pub fn check_checkout_amount(
+    expected_amount: MinorUnit,
+    expected_currency: api_enums::Currency,
+    response: &WaveCheckoutSessionResponse,
+) -> Option<WaveAmountMismatch> {
+    let expected_currency = expected_currency.to_string();
+    let amount_matches =
+        response.amount.trim().parse::<i64>().ok() == Some(expected_amount.get_amount_as_i64());
+    if amount_matches && response.currency.eq_ignore_ascii_case(&expected_currency) {
+        return None;
+    }
+    router_env::logger::warn!(
+        "Wave checkout session {} amount mismatch: expected={} {}, received={} {}",
+        response.id,
+        expected_amount.get_amount_as_i64(),
+        expected_currency,
+        response.amount,
+        response.currency
+    );
+    Some(WaveAmountMismatch {
+        expected_amount: expected_amount.get_amount_as_i64(),
+        received_amount: response.amount.clone(),
+        expected_currency,
+        received_currency: response.currency.clone(),
+    })
+}
+
+/// Wave details kept on the attempt as `connector_metadata` for the router to surface
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct WavePaymentConnectorMetadata {
+    pub wave_session_id: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub aggregated_merchant_id: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub launch_url: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub fee: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub net_amount: Option<String>,
+}
+
+impl WavePaymentConnectorMetadata {
+    /// `aggregated_merchant_id` is the one the session was actually created under, which can be a
+    /// per-payment override or none at all when an unconfirmed merchant was dropped
+    fn new(
+        session_id: &str,
+        aggregated_merchant_id: Option<String>,
+        launch_url: Option<&str>,
+    ) -> Self {
+        Self {
+            wave_session_id: session_id.to_string(),
+            aggregated_merchant_id,
+            launch_url: launch_url.map(str::to_string),
+            fee: None,
+            net_amount: None,
+        }
+    }
+
+    fn into_value(self) -> Option<serde_json::Value> {
+        serde_json::to_value(self).ok()
+    }
+}
+
 impl<F, T>
     TryFrom<ResponseRouterData<F, WaveCheckoutSessionResponse, T, PaymentsResponseData>>
     for RouterData<F, T, PaymentsResponseData>
@@ -831,11 +2371,30 @@ impl<F, T>
         item: ResponseRouterData<F, WaveCheckoutSessionResponse, T, PaymentsResponseData>,
     ) -> Result<Self, Self::Error> {
         let status = AttemptStatus::from(item.response.status.clone());
+        let connector_metadata = WavePaymentConnectorMetadata::new(
+            &item.response.id,
+            item.response.aggregated_merchant_id.clone(),
+            item.response.launch_url.as_deref(),
+        )
+        .into_value();
         let redirection_data = item.response.launch_url.and_then(|url_str| {
             Url::parse(&url_str)
                 .map(|url| RedirectForm::from((url, Method::Get)))
                 .ok()
         });
+        // An open session can only be paid through its launch URL
+        if redirection_data.is_none()
+            && matches!(
+                item.response.status,
+                WavePaymentStatus::Created | WavePaymentStatus::Pending
+            )
+        {
+            return Err(error_stack::report!(ConnectorError::ResponseDeserializationFailed)
+                .attach_printable(format!(
+                    "Wave session {} is {:?} without a usable launch_url",
+                    item.response.id, item.response.status
+                )));
+        }
 
         Ok(Self {
             status,
@@ -845,7 +2404,7 @@ impl<F, T>
                 ),
                 redirection_data: Box::new(redirection_data),
                 mandate_reference: Box::new(None),
-                connector_metadata: None,
+                connector_metadata,
                 network_txn_id: None,
                 connector_response_reference_id: item.response.reference,
                 incremental_authorization_allowed: None,
@@ -856,6 +2415,46 @@ impl<F, T>
     }
 }
 
+impl<F>
+    TryFrom<ResponseRouterData<F, WaveAggregatedMerchant, PaymentsPreProcessingData, PaymentsResponseData>>
+    for RouterData<F, PaymentsPreProcessingData, PaymentsResponseData>
+{
+    type Error = error_stack::Report<ConnectorError>;
+    fn try_from(
+        item: ResponseRouterData<F, WaveAggregatedMerchant, PaymentsPreProcessingData, PaymentsResponseData>,
+    ) -> Result<Self, Self::Error> {
+        // Authorize only routes to the merchant when its id comes back as `preprocessing_id`
+        if !item.response.status.is_usable() {
+            return Ok(Self {
+                response: Err(ErrorResponse {
+                    code: NO_ERROR_CODE.to_string(),
+                    message: format!(
+                        "Aggregated merchant {} is {}",
+                        item.response.id, item.response.status
+                    ),
+                    reason: None,
+                    status_code: item.http_code,
+                    attempt_status: None,
+                    connector_transaction_id: None,
+                    ..Default::default()
+                }),
+                ..item.data
+            });
+        }
+
+        Ok(Self {
+            preprocessing_id: Some(item.response.id.clone()),
+            response: Ok(PaymentsResponseData::PreProcessingResponse {
+                pre_processing_id: PreprocessingResponseId::PreProcessingId(item.response.id),
+                connector_metadata: None,
+                session_token: None,
+                connector_response_reference_id: None,
+            }),
+            ..item.data
+        })
+    }
+}
+
 impl<F, T>
     TryFrom<ResponseRouterData<F, WavePaymentsCancelResponse, T, PaymentsResponseData>>
     for RouterData<F, T, PaymentsResponseData>
@@ -884,20 +2483,105 @@ impl<F, T>
     }
 }
 
-impl<F, T>
-    TryFrom<ResponseRouterData<F, WavePaymentStatusResponse, T, PaymentsResponseData>>
-    for RouterData<F, T, PaymentsResponseData>
+impl<F>
+    TryFrom<ResponseRouterData<F, WavePaymentStatusResponse, PaymentsSyncData, PaymentsResponseData>>
+    for RouterData<F, PaymentsSyncData, PaymentsResponseData>
 {
     type Error = error_stack::Report<ConnectorError>;
     fn try_from(
-        item: ResponseRouterData<F, WavePaymentStatusResponse, T, PaymentsResponseData>,
+        item: ResponseRouterData<F, WavePaymentStatusResponse, PaymentsSyncData, PaymentsResponseData>,
     ) -> Result<Self, Self::Error> {
-        let status = AttemptStatus::from(item.response.status);
-        let redirection_data = item.response.launch_url.and_then(|url_str| {
+        // Sessions the payer never acted on stay `created` forever, fail them once the grace window passes
+        let grace_seconds = parse_wave_connector_metadata(item.data.connector_meta_data.as_ref())
+            .and_then(|meta| meta.created_status_grace_seconds);
+        if item.response.status == WavePaymentStatus::Created
+            && has_exceeded_age(
+                item.response.created_at,
+                grace_seconds,
+                common_utils::date_time::now(),
+            )
+        {
+            router_env::logger::info!(
+                "Wave session {} still created after grace window, marking as abandoned",
+                item.response.id
+            );
+            return Ok(Self {
+                status: AttemptStatus::Failure,
+                response: Err(ErrorResponse {
+                    code: NO_ERROR_CODE.to_string(),
+                    message: WAVE_ABANDONED_SESSION_REASON.to_string(),
+                    reason: Some(WAVE_ABANDONED_SESSION_REASON.to_string()),
+                    status_code: item.http_code,
+                    attempt_status: Some(AttemptStatus::Failure),
+                    connector_transaction_id: Some(item.response.id),
+                    ..Default::default()
+                }),
+                ..item.data
+            });
+        }
+
+        // Declined sessions carry Wave's decline code, surface it instead of a bare failure
+        if item.response.status == WavePaymentStatus::Failed {
+            if let Some(failure_reason) = item.response.failure_reason.clone() {
+                let decline_code = WaveDeclineCode::from_reason(&failure_reason);
+                return Ok(Self {
+                    status: AttemptStatus::Failure,
+                    response: Err(ErrorResponse {
+                        code: failure_reason.clone(),
+                        message: decline_code.message().to_string(),
+                        reason: Some(failure_reason),
+                        status_code: item.http_code,
+                        attempt_status: Some(AttemptStatus::Failure),
+                        connector_transaction_id: Some(item.response.id),
+                        ..Default::default()
+                    }),
+                    ..item.data
+                });
+            }
+        }
+
+        // A finished session must not send the payer back to Wave
+        let launch_url = if item.response.status.is_terminal() {
+            None
+        } else {
+            item.response.launch_url
+        };
+        // The sync result replaces what Authorize stored, so keep its values where Wave sends none
+        let stored = item
+            .data
+            .request
+            .connector_meta
+            .clone()
+            .and_then(|value| serde_json::from_value::<WavePaymentConnectorMetadata>(value).ok());
+        let connector_metadata = match stored {
+            Some(stored) => WavePaymentConnectorMetadata {
+                wave_session_id: item.response.id.clone(),
+                aggregated_merchant_id: item
+                    .response
+                    .aggregated_merchant_id
+                    .or(stored.aggregated_merchant_id),
+                launch_url: stored.launch_url,
+                // Fee and net amount let merchants see what Wave actually settles
+                fee: item.response.fee.or(stored.fee),
+                net_amount: item.response.net_amount.or(stored.net_amount),
+            },
+            None => WavePaymentConnectorMetadata {
+                fee: item.response.fee,
+                net_amount: item.response.net_amount,
+                ..WavePaymentConnectorMetadata::new(
+                    &item.response.id,
+                    item.response.aggregated_merchant_id,
+                    launch_url.as_deref(),
+                )
+            },
+        }
+        .into_value();
+        let redirection_data = launch_url.and_then(|url_str| {
             Url::parse(&url_str)
                 .map(|url| RedirectForm::from((url, Method::Get)))
                 .ok()
         });
+        let status = AttemptStatus::from(item.response.status);
 
         Ok(Self {
             status,
@@ -907,7 +2591,7 @@ impl<F, T>
                 ),
                 redirection_data: Box::new(redirection_data),
                 mandate_reference: Box::new(None),
-                connector_metadata: None,
+                connector_metadata,
                 network_txn_id: None,
                 connector_response_reference_id: item.response.reference,
                 incremental_authorization_allowed: None,
@@ -923,7 +2607,35 @@ impl<F> TryFrom<RefundsResponseRouterData<F, WaveRefundResponse>> for RefundsRou
     fn try_from(
         item: RefundsResponseRouterData<F, WaveRefundResponse>,
     ) -> Result<Self, Self::Error> {
-        let refund_status = RefundStatus::from(item.response.status);
+        let requested_currency = item.data.request.currency.to_string();
+        if !item.response.currency.eq_ignore_ascii_case(&requested_currency) {
+            return Err(error_stack::report!(ConnectorError::ResponseDeserializationFailed)
+                .attach_printable(format!(
+                    "Wave refund {} currency mismatch: requested={}, received={}",
+                    item.response.id, requested_currency, item.response.currency
+                )));
+        }
+
+        let mut refund_status = RefundStatus::from(item.response.status.clone());
+
+        // Wave can leave a refund processing indefinitely, hand it to manual review instead of polling forever
+        let max_age_seconds = parse_wave_connector_metadata(item.data.connector_meta_data.as_ref())
+            .and_then(|meta| meta.refund_pending_max_age_seconds);
+        if item.response.status == WaveRefundStatus::Processing
+            && has_exceeded_age(
+                item.response.created_at,
+                max_age_seconds,
+                common_utils::date_time::now(),
+            )
+        {
+            router_env::logger::warn!(
+                "Wave refund {} still processing after {:?} seconds, moving to manual review",
+                item.response.id,
+                max_age_seconds
+            );
+            refund_status = RefundStatus::ManualReview;
+        }
+
         Ok(Self {
             response: Ok(RefundsResponseData {
                 connector_refund_id: item.response.id,
@@ -939,26 +2651,277 @@ mod tests {
     use super::*;
     use common_enums::Currency;
     use common_utils::types::MinorUnit;
-    use hyperswitch_domain_models::router_data::ConnectorAuthType;
+    use hyperswitch_domain_models::{
+        router_data::ConnectorAuthType,
+        router_request_types::PaymentsSyncData,
+    };
     use masking::Secret;
     
-    #[test]
-    fn test_wave_auth_type_from_header_key() {
-        let auth_type = ConnectorAuthType::HeaderKey {
-            api_key: Secret::new("test_key".to_string()),
-        };
-        
-        let wave_auth = WaveAuthType::try_from(&auth_type).unwrap();
-        
-        assert_eq!(wave_auth.api_key.peek(), "test_key");
-        assert!(!wave_auth.aggregated_merchants_enabled);
-        assert!(!wave_auth.auto_create_aggregated_merchant);
-        assert_eq!(wave_auth.default_business_type, WaveBusinessType::Ecommerce);
-        assert_eq!(wave_auth.cache_ttl_seconds, 3600);
-    }
+    use crate::connectors::wave::tests::{
+        authorize_request_data, build_router_data, checkout_request_for, refunds_request_data,
+    };
     
-    #[test]
-    fn test_wave_auth_type_from_body_key_with_config() {
+    fn checkout_request(connector_meta_data: Option<serde_json::Value>) -> WaveCheckoutSessionRequest {
+        let mut router_data: PaymentsAuthorizeRouterData = build_router_data(authorize_request_data(1000));
+        router_data.connector_meta_data = connector_meta_data.map(Secret::new);
+        checkout_request_for(&router_data).unwrap()
+    }
+
+    const TEST_RETURN_URL: &str = "https://hyperswitch.example/payments/return";
+
+    #[test]
+    fn test_checkout_amount_reconciles_with_payment_amount() {
+        let request =
+            WaveCheckoutSessionRequest::new(MinorUnit::new(1000), Currency::XOF, TEST_RETURN_URL, false).unwrap();
+
+        assert!(reconcile_wave_checkout_amount(&request, MinorUnit::new(1000)).is_ok());
+    }
+
+    #[test]
+    fn test_checkout_amount_from_a_wrong_conversion_is_rejected() {
+        // A conversion that scaled the amount as if XOF had two decimals
+        let request =
+            WaveCheckoutSessionRequest::new(MinorUnit::new(100000), Currency::XOF, TEST_RETURN_URL, false).unwrap();
+
+        let error = reconcile_wave_checkout_amount(&request, MinorUnit::new(1000)).unwrap_err();
+
+        assert_eq!(
+            error.current_context(),
+            &ConnectorError::InvalidDataFormat { field_name: "amount" }
+        );
+    }
+
+    #[test]
+    fn test_new_checkout_request_matches_the_router_data_path() {
+        let request =
+            WaveCheckoutSessionRequest::new(MinorUnit::new(1000), Currency::XOF, TEST_RETURN_URL, false).unwrap();
+        let from_router_data = checkout_request(None);
+
+        assert_eq!(request.amount, from_router_data.amount);
+        assert_eq!(request.currency, from_router_data.currency);
+        assert_eq!(request.success_url, from_router_data.success_url);
+        assert_eq!(request.error_url, from_router_data.error_url);
+        assert_eq!(
+            serde_json::to_value(&request).unwrap(),
+            serde_json::json!({
+                "amount": "1000",
+                "currency": "XOF",
+                "error_url": "https://hyperswitch.example/payments/return?wave_status=error",
+                "success_url": "https://hyperswitch.example/payments/return?wave_status=success",
+                "aggregated_merchant_id": null
+            })
+        );
+    }
+
+    #[test]
+    fn test_new_checkout_request_builders_set_customer_and_aggregated_merchant() {
+        let request = WaveCheckoutSessionRequest::new(MinorUnit::new(2500), Currency::XOF, TEST_RETURN_URL, false)
+            .unwrap()
+            .with_customer(Some(WaveCustomer {
+                name: Some(Secret::new("Awa Diop".to_string())),
+                email: None,
+            }))
+            .with_aggregated_merchant(Some("am-test123".to_string()));
+
+        let body = serde_json::to_value(&request).unwrap();
+        assert_eq!(body["amount"], "2500");
+        assert_eq!(body["aggregated_merchant_id"], "am-test123");
+        assert_eq!(body["customer"], serde_json::json!({ "name": "Awa Diop" }));
+    }
+
+    #[test]
+    fn test_new_checkout_request_rejects_invalid_amounts() {
+        for amount in [0, -100] {
+            let error = WaveCheckoutSessionRequest::new(MinorUnit::new(amount), Currency::XOF, TEST_RETURN_URL, false)
+                .unwrap_err();
+            assert_eq!(
+                error.current_context(),
+                &ConnectorError::InvalidDataFormat { field_name: "amount" }
+            );
+        }
+    }
+
+    #[test]
+    fn test_checkout_urls_use_configured_success_and_error_urls() {
+        let request = checkout_request(Some(serde_json::json!({
+            "success_url": "https://shop.example.sn/paid",
+            "error_url": "https://shop.example.sn/failed"
+        })));
+
+        assert_eq!(request.success_url, Some("https://shop.example.sn/paid".to_string()));
+        assert_eq!(request.error_url, Some("https://shop.example.sn/failed".to_string()));
+    }
+
+    #[test]
+    fn test_checkout_rejects_configured_non_https_redirect_urls() {
+        for (meta, field_name) in [
+            (
+                serde_json::json!({ "success_url": "http://shop.example.sn/paid" }),
+                "success_url",
+            ),
+            (serde_json::json!({ "error_url": "not a url" }), "error_url"),
+        ] {
+            let mut router_data: PaymentsAuthorizeRouterData =
+                build_router_data(authorize_request_data(1000));
+            router_data.connector_meta_data = Some(Secret::new(meta));
+            let error = checkout_request_for(&router_data).unwrap_err();
+
+            assert_eq!(
+                error.current_context(),
+                &ConnectorError::InvalidDataFormat { field_name }
+            );
+        }
+    }
+
+    #[test]
+    fn test_new_checkout_request_rejects_non_https_return_url() {
+        let error = WaveCheckoutSessionRequest::new(
+            MinorUnit::new(1000),
+            Currency::XOF,
+            "http://hyperswitch.example/payments/return",
+            false,
+        )
+        .unwrap_err();
+
+        assert_eq!(
+            error.current_context(),
+            &ConnectorError::InvalidDataFormat { field_name: "return_url" }
+        );
+    }
+
+    #[test]
+    fn test_checkout_urls_default_to_tagged_return_url() {
+        let request = checkout_request(None);
+
+        assert_eq!(
+            request.success_url,
+            Some("https://hyperswitch.example/payments/return?wave_status=success".to_string())
+        );
+        assert_eq!(
+            request.error_url,
+            Some("https://hyperswitch.example/payments/return?wave_status=error".to_string())
+        );
+    }
+
+    #[test]
+    fn test_reference_echo_matching() {
+        assert_eq!(check_reference_echo("pay_wave_test_1", Some("pay_wave_test_1")), None);
+    }
+
+    #[test]
+    fn test_reference_echo_mismatch() {
+        assert_eq!(
+            check_reference_echo("pay_wave_test_1", Some("pay_other_1")),
+            Some(WaveReferenceMismatch {
+                expected: "pay_wave_test_1".to_string(),
+                received: Some("pay_other_1".to_string()),
+            })
+        );
+        assert_eq!(
+            check_reference_echo("pay_wave_test_1", None),
+            Some(WaveReferenceMismatch {
+                expected: "pay_wave_test_1".to_string(),
+                received: None,
+            })
+        );
+    }
+
+    #[test]
+    fn test_wave_amount_limit_boundaries() {
+        let metadata = WaveConnectorMetadata {
+            min_amount: Some(100),
+            max_amount: Some(1_000_000),
+            ..Default::default()
+        };
+        let check = |amount| validate_wave_amount_limits(MinorUnit::new(amount), Some(&metadata));
+
+        assert_eq!(
+            check(99),
+            Err(WaveAmountLimitError::AmountTooLow { amount: 99, min_amount: 100 })
+        );
+        assert_eq!(check(100), Ok(()));
+        assert_eq!(check(1_000_000), Ok(()));
+        assert_eq!(
+            check(1_000_001),
+            Err(WaveAmountLimitError::AmountTooHigh { amount: 1_000_001, max_amount: 1_000_000 })
+        );
+    }
+
+    #[test]
+    fn test_wave_amount_limit_errors_are_distinct_and_carry_the_limit() {
+        assert_eq!(
+            ConnectorError::from(WaveAmountLimitError::AmountTooLow { amount: 99, min_amount: 100 }),
+            ConnectorError::NotSupported {
+                message: "Amount 99 is below the Wave minimum of 100".to_string(),
+                connector: "wave",
+            }
+        );
+        assert_eq!(
+            ConnectorError::from(WaveAmountLimitError::AmountTooHigh {
+                amount: 1_000_001,
+                max_amount: 1_000_000
+            }),
+            ConnectorError::NotSupported {
+                message: "Amount 1000001 is above the Wave maximum of 1000000".to_string(),
+                connector: "wave",
+            }
+        );
+    }
+
+    #[test]
+    fn test_wave_amount_limits_default_to_xof_limits() {
+        assert!(validate_wave_amount_limits(MinorUnit::new(WAVE_DEFAULT_MIN_AMOUNT - 1), None).is_err());
+        assert!(validate_wave_amount_limits(MinorUnit::new(WAVE_DEFAULT_MAX_AMOUNT), None).is_ok());
+        assert!(validate_wave_amount_limits(MinorUnit::new(WAVE_DEFAULT_MAX_AMOUNT + 1), None).is_err());
+    }
+
+    #[test]
+    fn test_wave_id_construction() {
+        assert_eq!(WaveSessionId::new("cos-18qq25rgr100a").unwrap().as_ref(), "cos-18qq25rgr100a");
+        assert!(WaveSessionId::new("").is_err());
+        assert!(WaveSessionId::new("cos-1/../refunds").is_err());
+
+        assert!(WaveTransactionId::new("T_ABC123").is_ok());
+        assert!(WaveTransactionId::new("T ABC").is_err());
+
+        assert!(WaveRefundId::new("rf-123").is_ok());
+        assert_eq!(
+            WaveRefundId::new("").unwrap_err().current_context(),
+            &ConnectorError::MissingConnectorRefundID
+        );
+        assert!(WaveRefundId::new("rf-1/../checkout").is_err());
+        for malformed in ["rf-", "T_ABC123", "cos-18qq25rgr100a", "RF-123"] {
+            assert_eq!(
+                WaveRefundId::new(malformed).unwrap_err().current_context(),
+                &ConnectorError::InvalidDataFormat {
+                    field_name: "connector_refund_id"
+                }
+            );
+        }
+
+        assert!(WaveAggregatedMerchantId::new("am-test123").is_ok());
+        assert!(WaveAggregatedMerchantId::new("am-").is_err());
+        assert!(WaveAggregatedMerchantId::new("test123").is_err());
+        assert!(WaveAggregatedMerchantId::new("am-test?x=1").is_err());
+    }
+
+    #[test]
+    fn test_wave_auth_type_from_header_key() {
+        let auth_type = ConnectorAuthType::HeaderKey {
+            api_key: Secret::new("test_key".to_string()),
+        };
+        
+        let wave_auth = WaveAuthType::try_from(&auth_type).unwrap();
+        
+        assert_eq!(wave_auth.api_key.peek(), "test_key");
+        assert!(!wave_auth.aggregated_merchants_enabled);
+        assert!(!wave_auth.auto_create_aggregated_merchant);
+        assert_eq!(wave_auth.default_business_type, WaveBusinessType::Ecommerce);
+        assert_eq!(wave_auth.cache_ttl_seconds, 3600);
+    }
+    
+    #[test]
+    fn test_wave_auth_type_from_body_key_with_config() {
         let config = WaveAggregatedMerchantConfig {
             enabled: true,
             auto_create_on_profile_creation: true,
@@ -982,6 +2945,71 @@ mod tests {
         assert_eq!(wave_auth.cache_ttl_seconds, 7200);
     }
     
+    #[test]
+    fn test_wave_auth_type_from_signature_key_carries_webhook_secret() {
+        let config = WaveAggregatedMerchantConfig {
+            enabled: true,
+            auto_create_on_profile_creation: false,
+            default_business_type: WaveBusinessType::Marketplace,
+            cache_ttl_seconds: 600,
+        };
+        let auth_type = ConnectorAuthType::SignatureKey {
+            api_key: Secret::new("test_key".to_string()),
+            key1: Secret::new("whsec_test".to_string()),
+            api_secret: Secret::new(serde_json::to_string(&config).unwrap()),
+        };
+
+        let wave_auth = WaveAuthType::try_from(&auth_type).unwrap();
+
+        assert_eq!(wave_auth.api_key.peek(), "test_key");
+        assert_eq!(
+            wave_auth.webhook_secret.as_ref().map(|secret| secret.peek().as_str()),
+            Some("whsec_test")
+        );
+        assert!(wave_auth.aggregated_merchants_enabled);
+        assert_eq!(wave_auth.default_business_type, WaveBusinessType::Marketplace);
+        assert_eq!(wave_auth.cache_ttl_seconds, 600);
+    }
+
+    #[test]
+    fn test_wave_auth_type_without_signature_key_has_no_webhook_secret() {
+        let header_key = ConnectorAuthType::HeaderKey {
+            api_key: Secret::new("test_key".to_string()),
+        };
+        let body_key = ConnectorAuthType::BodyKey {
+            api_key: Secret::new("test_key".to_string()),
+            key1: Secret::new("{}".to_string()),
+        };
+
+        assert!(WaveAuthType::try_from(&header_key).unwrap().webhook_secret.is_none());
+        assert!(WaveAuthType::try_from(&body_key).unwrap().webhook_secret.is_none());
+    }
+
+    #[test]
+    fn test_wave_webhook_signature_header_parsing() {
+        let signature = WaveWebhookSignature::parse("t=1639081943,v1=0a0b,v1=ff").unwrap();
+
+        assert_eq!(signature.timestamp, "1639081943");
+        assert_eq!(signature.signatures, vec![vec![0x0a, 0x0b], vec![0xff]]);
+        assert_eq!(signature.signed_message(b"{}"), b"1639081943{}".to_vec());
+        assert!(WaveWebhookSignature::parse("v1=0a0b").is_none());
+        assert!(WaveWebhookSignature::parse("t=1639081943").is_none());
+        assert!(WaveWebhookSignature::parse("t=1639081943,v1=not-hex").is_none());
+    }
+
+    #[test]
+    fn test_wave_webhook_signature_timestamp_tolerance() {
+        let signature = WaveWebhookSignature::parse("t=1639081943,v1=0a0b").unwrap();
+
+        assert!(signature.is_within_tolerance(1639081943, 300));
+        assert!(signature.is_within_tolerance(1639081943 + 300, 300));
+        assert!(!signature.is_within_tolerance(1639081943 + 301, 300));
+        assert!(!signature.is_within_tolerance(1639081943 - 301, 300));
+
+        let unparseable = WaveWebhookSignature::parse("t=yesterday,v1=0a0b").unwrap();
+        assert!(!unparseable.is_within_tolerance(1639081943, 300));
+    }
+
     #[test]
     fn test_wave_business_type_default() {
         let business_type = WaveBusinessType::default();
@@ -997,6 +3025,85 @@ mod tests {
         let deserialized: WaveBusinessType = serde_json::from_str(&serialized).unwrap();
         assert_eq!(deserialized, WaveBusinessType::Marketplace);
     }
+
+    #[test]
+    fn test_aggregated_merchant_request_builder_valid() {
+        let request = WaveAggregatedMerchantRequestBuilder::new()
+            .name("Boutique Dakar")
+            .business_type(WaveBusinessType::Marketplace)
+            .business_description("Marketplace for local artisans")
+            .website_url(Some("https://boutique.example.sn".to_string()))
+            .build()
+            .unwrap();
+
+        assert_eq!(request.name, "Boutique Dakar");
+        assert_eq!(request.business_type, WaveBusinessType::Marketplace);
+        assert_eq!(request.business_description, "Marketplace for local artisans");
+        assert_eq!(request.website_url, Some("https://boutique.example.sn".to_string()));
+    }
+
+    #[test]
+    fn test_aggregated_merchant_request_builder_rejects_invalid_fields() {
+        let valid = || {
+            WaveAggregatedMerchantRequestBuilder::new()
+                .name("Boutique Dakar")
+                .business_description("Marketplace for local artisans")
+        };
+
+        // Missing name
+        assert!(WaveAggregatedMerchantRequestBuilder::new()
+            .business_description("Marketplace for local artisans")
+            .build()
+            .is_err());
+        // Name too long
+        assert!(valid().name("a".repeat(256)).build().is_err());
+        // Missing or blank description
+        assert!(WaveAggregatedMerchantRequestBuilder::new()
+            .name("Boutique Dakar")
+            .build()
+            .is_err());
+        assert!(valid().business_description("   ").build().is_err());
+        // Malformed URLs
+        assert!(valid()
+            .website_url(Some("boutique.example.sn".to_string()))
+            .build()
+            .is_err());
+        assert!(valid()
+            .website_url(Some("https://".to_string()))
+            .build()
+            .is_err());
+    }
+
+    #[test]
+    fn test_cache_key_is_scoped_by_base_url() {
+        let api_key = Secret::new("wave_test_key".to_string());
+
+        let sandbox_key =
+            get_aggregated_merchant_cache_key(&api_key, "https://sandbox.wave.com/", "am-test123");
+        let production_key =
+            get_aggregated_merchant_cache_key(&api_key, "https://api.wave.com/", "am-test123");
+
+        assert_ne!(sandbox_key, production_key);
+        assert_eq!(
+            production_key,
+            get_aggregated_merchant_cache_key(&api_key, "https://api.wave.com", "am-test123")
+        );
+        assert!(!production_key.contains("wave_test_key"));
+    }
+
+    #[test]
+    fn test_unknown_wave_business_type_deserializes_as_other() {
+        let merchant: WaveAggregatedMerchant = serde_json::from_value(serde_json::json!({
+            "id": "am-test123",
+            "name": "Test Merchant",
+            "business_type": "gaming",
+            "business_description": "Online games",
+            "status": "active"
+        }))
+        .unwrap();
+
+        assert_eq!(merchant.business_type, WaveBusinessType::Other);
+    }
     
     #[test]
     fn test_wave_connector_metadata_validation_valid() {
@@ -1006,12 +3113,26 @@ mod tests {
             auto_create_aggregated_merchant: Some(false),
             business_type: Some(WaveBusinessType::Ecommerce),
             business_description: Some("Test business".to_string()),
-            manager_name: Some("John Doe".to_string()),
-            business_registration_identifier: Some("REG123".to_string()),
+            manager_name: Some(Secret::new("John Doe".to_string())),
+            business_registration_identifier: Some(Secret::new("REG123".to_string())),
             business_sector: Some("Technology".to_string()),
             website_url: Some("https://example.com".to_string()),
             cache_enabled: Some(true),
             cache_ttl_seconds: Some(3600),
+            created_status_grace_seconds: Some(1800),
+            min_amount: Some(100),
+            max_amount: Some(1_500_000),
+            success_url: None,
+            error_url: None,
+            locale: None,
+            refund_pending_max_age_seconds: None,
+            webhook_id: None,
+            require_customer_email: None,
+            business_description_template: None,
+            marketplace_commission: None,
+            include_request_body_in_events: None,
+            preferred_provider: None,
+            on_resolution_failure: None,
         };
         
         let result = validate_wave_connector_metadata(&metadata);
@@ -1083,11 +3204,11 @@ mod tests {
         let request = WaveAggregatedMerchantRequest {
             name: "Test Merchant".to_string(),
             business_type: WaveBusinessType::Ecommerce,
-            business_registration_identifier: Some("REG123".to_string()),
+            business_registration_identifier: Some(Secret::new("REG123".to_string())),
             business_sector: Some("Technology".to_string()),
             website_url: Some("https://example.com".to_string()),
             business_description: "Valid business description".to_string(),
-            manager_name: Some("John Doe".to_string()),
+            manager_name: Some(Secret::new("John Doe".to_string())),
         };
         
         let result = validate_wave_aggregated_merchant_request(&request);
@@ -1145,4 +3266,1443 @@ mod tests {
             _ => panic!("Expected ProcessingStepFailed error"),
         }
     }
+    
+    fn created_status_response(created_at: PrimitiveDateTime) -> WavePaymentStatusResponse {
+        WavePaymentStatusResponse {
+            id: "cos-test123".to_string(),
+            status: WavePaymentStatus::Created,
+            amount: "1000".to_string(),
+            currency: "XOF".to_string(),
+            reference: None,
+            launch_url: Some("https://pay.wave.com/c/cos-test123".to_string()),
+            aggregated_merchant_id: None,
+            created_at: Some(created_at),
+            failure_reason: None,
+            fee: None,
+            net_amount: None,
+        }
+    }
+    
+    fn psync_router_data_with_grace(grace_seconds: u64) -> PaymentsSyncRouterData {
+        let mut router_data: PaymentsSyncRouterData = build_router_data(PaymentsSyncData::default());
+        router_data.connector_meta_data = Some(Secret::new(serde_json::json!({
+            "created_status_grace_seconds": grace_seconds
+        })));
+        router_data
+    }
+    
+    fn status_response(amount: &str) -> WavePaymentStatusResponse {
+        WavePaymentStatusResponse {
+            id: "cos-test123".to_string(),
+            status: WavePaymentStatus::Completed,
+            amount: amount.to_string(),
+            currency: "XOF".to_string(),
+            reference: None,
+            launch_url: None,
+            aggregated_merchant_id: None,
+            created_at: None,
+            failure_reason: None,
+            fee: None,
+            net_amount: None,
+        }
+    }
+
+    #[test]
+    fn test_completed_sync_surfaces_fee_and_net_amount() {
+        let response: WavePaymentStatusResponse = serde_json::from_value(serde_json::json!({
+            "id": "cos-test123",
+            "status": "completed",
+            "amount": "1000",
+            "currency": "XOF",
+            "reference": null,
+            "launch_url": "https://pay.wave.com/c/cos-test123",
+            "fee": "10",
+            "net_amount": "990"
+        }))
+        .unwrap();
+        assert_eq!(response.fee.as_deref(), Some("10"));
+        assert_eq!(response.net_amount.as_deref(), Some("990"));
+
+        let router_data = PaymentsSyncRouterData::try_from(ResponseRouterData {
+            response,
+            data: build_router_data(PaymentsSyncData::default()),
+            http_code: 200,
+        })
+        .unwrap();
+
+        assert_eq!(router_data.status, AttemptStatus::Charged);
+        let connector_metadata = match router_data.response.unwrap() {
+            PaymentsResponseData::TransactionResponse {
+                connector_metadata, ..
+            } => connector_metadata.unwrap(),
+            _ => panic!("Expected TransactionResponse"),
+        };
+        assert_eq!(
+            connector_metadata,
+            serde_json::json!({
+                "wave_session_id": "cos-test123",
+                "fee": "10",
+                "net_amount": "990"
+            })
+        );
+    }
+
+    #[test]
+    fn test_completed_sync_keeps_metadata_stored_by_authorize() {
+        let router_data = PaymentsSyncRouterData::try_from(ResponseRouterData {
+            response: WavePaymentStatusResponse {
+                fee: Some("10".to_string()),
+                net_amount: Some("990".to_string()),
+                ..status_response("1000")
+            },
+            data: build_router_data(PaymentsSyncData {
+                connector_meta: Some(serde_json::json!({
+                    "wave_session_id": "cos-test123",
+                    "aggregated_merchant_id": "am-test123",
+                    "launch_url": "https://pay.wave.com/c/cos-test123"
+                })),
+                ..Default::default()
+            }),
+            http_code: 200,
+        })
+        .unwrap();
+
+        let (redirection_data, connector_metadata) = match router_data.response.unwrap() {
+            PaymentsResponseData::TransactionResponse {
+                redirection_data,
+                connector_metadata,
+                ..
+            } => (redirection_data, connector_metadata.unwrap()),
+            _ => panic!("Expected TransactionResponse"),
+        };
+        assert!(redirection_data.is_none());
+        assert_eq!(
+            connector_metadata,
+            serde_json::json!({
+                "wave_session_id": "cos-test123",
+                "aggregated_merchant_id": "am-test123",
+                "launch_url": "https://pay.wave.com/c/cos-test123",
+                "fee": "10",
+                "net_amount": "990"
+            })
+        );
+    }
+
+    #[test]
+    fn test_sync_without_fee_omits_settlement_fields() {
+        let router_data = PaymentsSyncRouterData::try_from(ResponseRouterData {
+            response: status_response("1000"),
+            data: build_router_data(PaymentsSyncData::default()),
+            http_code: 200,
+        })
+        .unwrap();
+
+        let connector_metadata = match router_data.response.unwrap() {
+            PaymentsResponseData::TransactionResponse {
+                connector_metadata, ..
+            } => connector_metadata.unwrap(),
+            _ => panic!("Expected TransactionResponse"),
+        };
+        assert!(connector_metadata.get("fee").is_none());
+        assert!(connector_metadata.get("net_amount").is_none());
+    }
+
+    #[test]
+    fn test_synced_amount_reconciliation() {
+        let router_data: PaymentsSyncRouterData = build_router_data(PaymentsSyncData {
+            amount: MinorUnit::new(1000),
+            currency: api_enums::Currency::XOF,
+            ..Default::default()
+        });
+        let item = WaveRouterData::try_from((
+            &api::CurrencyUnit::Minor,
+            router_data.request.currency,
+            router_data.request.amount,
+            &router_data,
+        ))
+        .unwrap();
+
+        assert!(reconcile_synced_amount(&item, &status_response("1000")));
+        assert!(!reconcile_synced_amount(&item, &status_response("900")));
+        assert!(!reconcile_synced_amount(&item, &status_response("not-a-number")));
+    }
+
+    #[test]
+    fn test_stale_created_session_is_marked_abandoned() {
+        let created_at = common_utils::date_time::now() - time::Duration::hours(2);
+        
+        let router_data = PaymentsSyncRouterData::try_from(ResponseRouterData {
+            response: created_status_response(created_at),
+            data: psync_router_data_with_grace(1800),
+            http_code: 200,
+        })
+        .unwrap();
+        
+        assert_eq!(router_data.status, AttemptStatus::Failure);
+        let error = router_data.response.unwrap_err();
+        assert_eq!(error.reason, Some(WAVE_ABANDONED_SESSION_REASON.to_string()));
+    }
+    
+    #[test]
+    fn test_created_session_within_grace_awaits_payer() {
+        let created_at = common_utils::date_time::now() - time::Duration::minutes(5);
+        
+        let router_data = PaymentsSyncRouterData::try_from(ResponseRouterData {
+            response: created_status_response(created_at),
+            data: psync_router_data_with_grace(1800),
+            http_code: 200,
+        })
+        .unwrap();
+        
+        assert_eq!(router_data.status, AttemptStatus::AuthenticationPending);
+        assert!(router_data.response.is_ok());
+    }
+
+    #[test]
+    fn test_aggregated_merchant_debug_output_masks_personal_fields() {
+        let metadata = WaveConnectorMetadata {
+            manager_name: Some(Secret::new("John Doe".to_string())),
+            business_registration_identifier: Some(Secret::new("REG123".to_string())),
+            ..Default::default()
+        };
+        let merchant: WaveAggregatedMerchant = serde_json::from_value(serde_json::json!({
+            "id": "am-test123",
+            "name": "Test Merchant",
+            "business_type": "ecommerce",
+            "business_registration_identifier": "REG123",
+            "business_description": "Online retail",
+            "manager_name": "John Doe",
+            "status": "active"
+        }))
+        .unwrap();
+
+        for output in [format!("{metadata:?}"), format!("{merchant:?}")] {
+            assert!(!output.contains("John Doe"));
+            assert!(!output.contains("REG123"));
+        }
+        assert_eq!(
+            merchant.manager_name.as_ref().map(|name| name.peek().as_str()),
+            Some("John Doe")
+        );
+    }
+
+    fn wave_error_body(code: &str) -> String {
+        serde_json::json!({ "code": code, "message": "Wave rejected the request" }).to_string()
+    }
+
+    fn processing_step_message(error: ConnectorError) -> String {
+        match error {
+            ConnectorError::ProcessingStepFailed(Some(message)) => {
+                String::from_utf8_lossy(&message).into_owned()
+            }
+            other => panic!("Expected ProcessingStepFailed with a message, got {other:?}"),
+        }
+    }
+
+    #[test]
+    fn test_wave_error_code_deserializes_known_and_unknown_codes() {
+        let codes: Vec<WaveErrorCode> = serde_json::from_value(serde_json::json!([
+            "AGGREGATED_MERCHANT_NOT_FOUND",
+            "INVALID_BUSINESS_TYPE",
+            "SOMETHING_NEW"
+        ]))
+        .unwrap();
+
+        assert_eq!(
+            codes,
+            vec![
+                WaveErrorCode::AggregatedMerchantNotFound,
+                WaveErrorCode::InvalidBusinessType,
+                WaveErrorCode::Unknown,
+            ]
+        );
+    }
+
+    #[test]
+    fn test_parse_wave_api_error_maps_known_codes() {
+        let not_found = processing_step_message(parse_wave_api_error(
+            404,
+            &wave_error_body("AGGREGATED_MERCHANT_NOT_FOUND"),
+        ));
+        assert!(not_found.contains("Aggregated merchant not found"));
+
+        let invalid_business_type = processing_step_message(parse_wave_api_error(
+            400,
+            &wave_error_body("INVALID_BUSINESS_TYPE"),
+        ));
+        assert!(invalid_business_type.contains("Invalid aggregated merchant configuration"));
+    }
+
+    #[test]
+    fn test_wave_error_response_parses_flat_and_nested_envelopes() {
+        let flat = serde_json::json!({
+            "code": "INVALID_BUSINESS_TYPE",
+            "message": "Business type is not allowed",
+            "details": [{ "loc": ["business_type"], "msg": "unknown value" }]
+        });
+        let nested = serde_json::json!({ "error": flat.clone() });
+
+        for body in [flat, nested] {
+            let error = WaveErrorResponse::parse(body.to_string().as_bytes()).unwrap();
+            assert_eq!(error.error_code(), WaveErrorCode::InvalidBusinessType);
+            assert_eq!(error.message, "Business type is not allowed");
+            assert_eq!(error.details.unwrap()[0].msg, "unknown value");
+        }
+    }
+
+    #[test]
+    fn test_wave_error_response_keeps_bare_error_message() {
+        let error = WaveErrorResponse::parse(br#"{"error":"boom"}"#).unwrap();
+
+        assert_eq!(error.code, None);
+        assert_eq!(error.message, "boom");
+    }
+
+    #[test]
+    fn test_wave_error_response_rejects_body_without_code_or_message() {
+        assert!(WaveErrorResponse::parse(br#"{"status":"failed"}"#).is_err());
+    }
+
+    #[test]
+    fn test_parse_wave_api_error_maps_codes_from_nested_envelope() {
+        let body = serde_json::json!({
+            "error": {
+                "code": "AGGREGATED_MERCHANT_NOT_FOUND",
+                "message": "Merchant not found"
+            }
+        });
+
+        let message = processing_step_message(parse_wave_api_error(404, &body.to_string()));
+
+        assert!(message.contains("Aggregated merchant not found"));
+    }
+
+    #[test]
+    fn test_parse_wave_api_error_unknown_code_falls_through_to_generic_error() {
+        let message = processing_step_message(parse_wave_api_error(
+            400,
+            &wave_error_body("SOMETHING_NEW"),
+        ));
+
+        assert_eq!(message, "Wave API error: 400 - Wave rejected the request");
+    }
+
+    #[test]
+    fn test_checkout_reference_differs_per_attempt() {
+        let reference_for_attempt = |attempt_id: &str| {
+            let mut router_data: PaymentsAuthorizeRouterData = build_router_data(authorize_request_data(1000));
+            router_data.connector_request_reference_id = "pay_wave_test".to_string();
+            router_data.attempt_id = attempt_id.to_string();
+            checkout_request_for(&router_data)
+                .unwrap()
+                .reference
+        };
+
+        let first_attempt = reference_for_attempt("pay_wave_test_1");
+        let second_attempt = reference_for_attempt("pay_wave_test_2");
+
+        assert_eq!(first_attempt, Some("pay_wave_test_1".to_string()));
+        assert_eq!(second_attempt, Some("pay_wave_test_2".to_string()));
+        assert_eq!(first_attempt, reference_for_attempt("pay_wave_test_1"));
+    }
+
+    #[test]
+    fn test_checkout_reference_appends_attempt_to_merchant_reference() {
+        let mut router_data: PaymentsAuthorizeRouterData = build_router_data(authorize_request_data(1000));
+        router_data.connector_request_reference_id = "order_42".to_string();
+
+        assert_eq!(get_wave_checkout_reference(&router_data), "order_42_pay_wave_test_1");
+    }
+
+    #[test]
+    fn test_parse_wave_connector_metadata_rejects_array_metadata() {
+        let metadata = Secret::new(serde_json::json!([{ "aggregated_merchant_id": "am-test123" }]));
+
+        assert_eq!(non_object_metadata_shape(metadata.peek()), Some("an array"));
+        assert!(parse_wave_connector_metadata(Some(&metadata)).is_none());
+    }
+
+    #[test]
+    fn test_parse_wave_connector_metadata_rejects_string_metadata() {
+        let metadata = Secret::new(serde_json::json!("am-test123"));
+
+        assert_eq!(non_object_metadata_shape(metadata.peek()), Some("a string"));
+        assert!(parse_wave_connector_metadata(Some(&metadata)).is_none());
+    }
+
+    #[test]
+    fn test_parse_wave_connector_metadata_accepts_object_metadata() {
+        let metadata = Secret::new(serde_json::json!({ "aggregated_merchant_id": "am-test123" }));
+
+        assert_eq!(non_object_metadata_shape(metadata.peek()), None);
+        let parsed = parse_wave_connector_metadata(Some(&metadata)).unwrap();
+        assert_eq!(parsed.aggregated_merchant_id, Some("am-test123".to_string()));
+    }
+
+    #[test]
+    fn test_checkout_locale_is_sent_from_request_locale() {
+        let mut router_data: PaymentsAuthorizeRouterData = build_router_data(authorize_request_data(1000));
+        router_data.request.locale = Some("fr-SN".to_string());
+        let body = serde_json::to_value(checkout_request_for(&router_data).unwrap()).unwrap();
+
+        assert_eq!(body["locale"], "fr");
+    }
+
+    #[test]
+    fn test_checkout_locale_falls_back_to_metadata_then_xof_default() {
+        let metadata = WaveConnectorMetadata {
+            locale: Some("en".to_string()),
+            ..Default::default()
+        };
+
+        assert_eq!(
+            get_wave_checkout_locale(None, Some(&metadata), Currency::XOF),
+            Some("en".to_string())
+        );
+        assert_eq!(get_wave_checkout_locale(None, None, Currency::XOF), Some("fr".to_string()));
+        assert_eq!(get_wave_checkout_locale(None, None, Currency::USD), None);
+    }
+
+    fn refund_response(currency: &str) -> WaveRefundResponse {
+        WaveRefundResponse {
+            id: "rf-test123".to_string(),
+            status: WaveRefundStatus::Completed,
+            amount: "500".to_string(),
+            currency: currency.to_string(),
+            transaction_id: Some("T_test123".to_string()),
+            created_at: None,
+        }
+    }
+
+    #[test]
+    fn test_refund_response_with_matching_currency_is_accepted() {
+        let router_data: RefundsRouterData<Execute> = build_router_data(refunds_request_data(500));
+
+        let refunded = RefundsRouterData::try_from(RefundsResponseRouterData {
+            response: refund_response("XOF"),
+            data: router_data,
+            http_code: 200,
+        })
+        .unwrap();
+
+        let response = refunded.response.unwrap();
+        assert_eq!(response.connector_refund_id, "rf-test123");
+        assert_eq!(response.refund_status, RefundStatus::Success);
+    }
+
+    #[test]
+    fn test_refund_response_with_different_currency_is_rejected() {
+        let router_data: RefundsRouterData<Execute> = build_router_data(refunds_request_data(500));
+
+        let error = RefundsRouterData::try_from(RefundsResponseRouterData {
+            response: refund_response("EUR"),
+            data: router_data,
+            http_code: 200,
+        })
+        .unwrap_err();
+
+        assert_eq!(error.current_context(), &ConnectorError::ResponseDeserializationFailed);
+    }
+
+    fn refund_pending_since(created_at: PrimitiveDateTime) -> RefundStatus {
+        let mut router_data: RefundsRouterData<Execute> = build_router_data(refunds_request_data(500));
+        router_data.connector_meta_data = Some(Secret::new(serde_json::json!({
+            "refund_pending_max_age_seconds": 86400
+        })));
+        let response = WaveRefundResponse {
+            status: WaveRefundStatus::Processing,
+            created_at: Some(created_at),
+            ..refund_response("XOF")
+        };
+
+        RefundsRouterData::try_from(RefundsResponseRouterData {
+            response,
+            data: router_data,
+            http_code: 200,
+        })
+        .unwrap()
+        .response
+        .unwrap()
+        .refund_status
+    }
+
+    #[test]
+    fn test_old_pending_refund_moves_to_manual_review() {
+        let created_at = common_utils::date_time::now() - time::Duration::days(3);
+
+        assert_eq!(refund_pending_since(created_at), RefundStatus::ManualReview);
+    }
+
+    #[test]
+    fn test_recent_pending_refund_stays_pending() {
+        let created_at = common_utils::date_time::now() - time::Duration::hours(1);
+
+        assert_eq!(refund_pending_since(created_at), RefundStatus::Pending);
+    }
+
+    fn dispatch_event(event_type: &str) -> (IncomingWebhookEvent, Option<ObjectReferenceId>) {
+        let event: WaveWebhookEvent = serde_json::from_value(serde_json::json!(event_type)).unwrap();
+        event.dispatch("obj-test123")
+    }
+
+    fn is_payment_reference(reference: &Option<ObjectReferenceId>) -> bool {
+        matches!(
+            reference,
+            Some(ObjectReferenceId::PaymentId(PaymentIdType::ConnectorTransactionId(id))) if id == "obj-test123"
+        )
+    }
+
+    fn is_refund_reference(reference: &Option<ObjectReferenceId>) -> bool {
+        matches!(
+            reference,
+            Some(ObjectReferenceId::RefundId(RefundIdType::ConnectorRefundId(id))) if id == "obj-test123"
+        )
+    }
+
+    #[test]
+    fn test_checkout_webhook_events_dispatch_to_payment_references() {
+        let cases = [
+            ("checkout.session.completed", IncomingWebhookEvent::PaymentIntentSuccess),
+            ("checkout.session.payment_failed", IncomingWebhookEvent::PaymentIntentFailure),
+            ("checkout.session.expired", IncomingWebhookEvent::PaymentIntentExpired),
+        ];
+
+        for (event_type, expected_event) in cases {
+            let (event, reference) = dispatch_event(event_type);
+            assert_eq!(event, expected_event, "{event_type}");
+            assert!(is_payment_reference(&reference), "{event_type}");
+        }
+    }
+
+    #[test]
+    fn test_refund_webhook_events_dispatch_to_refund_references() {
+        let cases = [
+            ("refund.completed", IncomingWebhookEvent::RefundSuccess),
+            ("refund.failed", IncomingWebhookEvent::RefundFailure),
+        ];
+
+        for (event_type, expected_event) in cases {
+            let (event, reference) = dispatch_event(event_type);
+            assert_eq!(event, expected_event, "{event_type}");
+            assert!(is_refund_reference(&reference), "{event_type}");
+        }
+    }
+
+    #[test]
+    fn test_unknown_webhook_event_dispatches_as_not_supported() {
+        let (event, reference) = dispatch_event("merchant.loyalty_points_awarded");
+
+        assert_eq!(event, IncomingWebhookEvent::EventNotSupported);
+        assert!(reference.is_none());
+    }
+
+    fn router_data_paying_with(payment_method_data: PaymentMethodData) -> PaymentsAuthorizeRouterData {
+        let mut router_data: PaymentsAuthorizeRouterData = build_router_data(authorize_request_data(1000));
+        router_data.request.payment_method_data = payment_method_data;
+        router_data
+    }
+
+    #[test]
+    fn test_mobile_money_payment_restricts_payer_mobile() {
+        let request = checkout_request_for(&router_data_paying_with(PaymentMethodData::MobilePayment(
+            MobilePaymentData::DirectCarrierBilling {
+                msisdn: "+221770000000".to_string(),
+                client_uid: None,
+            },
+        )))
+        .unwrap();
+
+        assert_eq!(
+            request.restrict_payer_mobile.as_ref().map(|mobile| mobile.peek().as_str()),
+            Some("+221770000000")
+        );
+        assert!(checkout_request(None).restrict_payer_mobile.is_none());
+    }
+
+    #[test]
+    fn test_unsupported_payment_method_lists_supported_methods() {
+        let error = checkout_request_for(&router_data_paying_with(PaymentMethodData::Wallet(
+            WalletData::PaypalRedirect(
+                hyperswitch_domain_models::payment_method_data::PaypalRedirection { email: None },
+            ),
+        )))
+        .unwrap_err();
+
+        match error.current_context() {
+            ConnectorError::NotImplemented(message) => {
+                assert!(message.contains("mobile_pay_redirect"));
+                assert!(message.contains("direct_carrier_billing"));
+            }
+            other => panic!("Expected NotImplemented, got {other:?}"),
+        }
+    }
+
+    #[test]
+    fn test_known_cancellation_reason_is_mapped() {
+        assert_eq!(
+            WaveCancellationReason::from_cancellation_reason("Requested-By-Customer"),
+            WaveCancellationReason::RequestedByCustomer
+        );
+        assert_eq!(
+            WaveCancellationReason::from_cancellation_reason("duplicate"),
+            WaveCancellationReason::Duplicate
+        );
+    }
+
+    #[test]
+    fn test_free_text_cancellation_reason_falls_back_to_other() {
+        let request = WavePaymentsCancelRequest {
+            reason: Some(WaveCancellationReason::from_cancellation_reason("merchant_timeout")),
+        };
+
+        assert_eq!(
+            serde_json::to_value(request).unwrap(),
+            serde_json::json!({ "reason": "other" })
+        );
+    }
+
+    #[test]
+    fn test_wave_payment_status_distinguishes_created_and_pending() {
+        assert_eq!(
+            AttemptStatus::from(WavePaymentStatus::Created),
+            AttemptStatus::AuthenticationPending
+        );
+        assert_eq!(AttemptStatus::from(WavePaymentStatus::Pending), AttemptStatus::Pending);
+    }
+
+    fn router_data_for_amount(amount: i64) -> PaymentsAuthorizeRouterData {
+        let mut router_data: PaymentsAuthorizeRouterData = build_router_data(authorize_request_data(1000));
+        router_data.request.minor_amount = MinorUnit::new(amount);
+        router_data
+    }
+
+    #[test]
+    fn test_out_of_range_amounts_are_rejected_before_the_body_is_built() {
+        for amount in [i64::MAX, 0, -500] {
+            let error = checkout_request_for(&router_data_for_amount(amount)).unwrap_err();
+            assert_eq!(
+                error.current_context(),
+                &ConnectorError::InvalidDataFormat { field_name: "amount" },
+                "{amount}"
+            );
+        }
+        assert_eq!(checkout_request_for(&router_data_for_amount(1000)).unwrap().amount, "1000");
+    }
+
+    fn psync_redirection_for(status: WavePaymentStatus) -> Option<RedirectForm> {
+        let router_data = PaymentsSyncRouterData::try_from(ResponseRouterData {
+            response: WavePaymentStatusResponse {
+                status,
+                launch_url: Some("https://pay.wave.com/c/cos-test123".to_string()),
+                ..status_response("1000")
+            },
+            data: build_router_data(PaymentsSyncData::default()),
+            http_code: 200,
+        })
+        .unwrap();
+        match router_data.response.unwrap() {
+            PaymentsResponseData::TransactionResponse {
+                redirection_data, ..
+            } => *redirection_data,
+            _ => panic!("Expected TransactionResponse"),
+        }
+    }
+
+    #[test]
+    fn test_terminal_sessions_drop_launch_url_redirection() {
+        for status in [
+            WavePaymentStatus::Completed,
+            WavePaymentStatus::Failed,
+            WavePaymentStatus::Cancelled,
+        ] {
+            assert!(psync_redirection_for(status.clone()).is_none(), "{status:?}");
+        }
+    }
+
+    #[test]
+    fn test_pending_sessions_keep_launch_url_redirection() {
+        for status in [WavePaymentStatus::Created, WavePaymentStatus::Pending] {
+            assert!(psync_redirection_for(status.clone()).is_some(), "{status:?}");
+        }
+    }
+
+    #[test]
+    fn test_missing_email_is_rejected_when_required() {
+        let mut router_data: PaymentsAuthorizeRouterData = build_router_data(authorize_request_data(1000));
+        router_data.connector_meta_data = Some(Secret::new(serde_json::json!({
+            "require_customer_email": true
+        })));
+        let error = checkout_request_for(&router_data).unwrap_err();
+        assert_eq!(
+            error.current_context(),
+            &ConnectorError::MissingRequiredField { field_name: "email" }
+        );
+    }
+
+    #[test]
+    fn test_missing_email_is_allowed_by_default() {
+        assert!(checkout_request(None).customer.is_none());
+    }
+
+    #[test]
+    fn test_aggregated_merchant_status_deserialization() {
+        for (raw, expected) in [
+            ("active", WaveAggregatedMerchantStatus::Active),
+            ("ACTIVE", WaveAggregatedMerchantStatus::Active),
+            ("suspended", WaveAggregatedMerchantStatus::Suspended),
+            ("pending_review", WaveAggregatedMerchantStatus::PendingReview),
+            ("Pending_Review", WaveAggregatedMerchantStatus::PendingReview),
+            ("archived", WaveAggregatedMerchantStatus::Unknown),
+        ] {
+            let status: WaveAggregatedMerchantStatus =
+                serde_json::from_value(serde_json::json!(raw)).unwrap();
+            assert_eq!(status, expected);
+            assert_eq!(status.is_usable(), raw.eq_ignore_ascii_case("active"));
+        }
+    }
+
+    #[test]
+    fn test_aggregated_merchant_info_conversion() {
+        let merchant: WaveAggregatedMerchant = serde_json::from_value(serde_json::json!({
+            "id": "am-test123",
+            "name": "Boutique Dakar",
+            "business_type": "marketplace",
+            "business_description": "Online retail",
+            "status": "pending_review",
+            "created_at": "2025-01-15T10:00:00+01:00"
+        }))
+        .unwrap();
+
+        let info = AggregatedMerchantInfo::from(merchant);
+        assert_eq!(info.id, "am-test123");
+        assert_eq!(info.name, "Boutique Dakar");
+        assert_eq!(info.business_type, "marketplace");
+        assert_eq!(info.status, "pending_review");
+        assert!(!info.is_active);
+        assert_eq!(
+            info.created_at,
+            Some(time::macros::datetime!(2025-01-15 09:00:00))
+        );
+    }
+
+    fn aggregated_merchant_with_timestamps(
+        created_at: serde_json::Value,
+        updated_at: serde_json::Value,
+    ) -> WaveAggregatedMerchant {
+        serde_json::from_value(serde_json::json!({
+            "id": "am-test123",
+            "name": "Boutique Dakar",
+            "business_type": "ecommerce",
+            "status": "active",
+            "created_at": created_at,
+            "updated_at": updated_at
+        }))
+        .unwrap()
+    }
+
+    #[test]
+    fn test_aggregated_merchant_timestamps_parse_to_utc() {
+        let merchant = aggregated_merchant_with_timestamps(
+            serde_json::json!("2025-01-15T10:00:00Z"),
+            serde_json::json!("2025-02-01T08:30:15+02:00"),
+        );
+
+        assert_eq!(
+            merchant.created_at,
+            Some(time::macros::datetime!(2025-01-15 10:00:00))
+        );
+        assert_eq!(
+            merchant.updated_at,
+            Some(time::macros::datetime!(2025-02-01 06:30:15))
+        );
+
+        let round_tripped: WaveAggregatedMerchant =
+            serde_json::from_value(serde_json::to_value(&merchant).unwrap()).unwrap();
+        assert_eq!(round_tripped.created_at, merchant.created_at);
+        assert_eq!(round_tripped.updated_at, merchant.updated_at);
+    }
+
+    #[test]
+    fn test_aggregated_merchant_null_or_missing_timestamps_are_none() {
+        let merchant =
+            aggregated_merchant_with_timestamps(serde_json::Value::Null, serde_json::Value::Null);
+        assert_eq!(merchant.created_at, None);
+        assert_eq!(merchant.updated_at, None);
+
+        let merchant: WaveAggregatedMerchant = serde_json::from_value(serde_json::json!({
+            "id": "am-test123",
+            "name": "Boutique Dakar",
+            "business_type": "ecommerce",
+            "status": "active"
+        }))
+        .unwrap();
+        assert_eq!(merchant.created_at, None);
+        assert_eq!(merchant.updated_at, None);
+    }
+
+    #[test]
+    fn test_aggregated_merchant_malformed_timestamps_are_dropped() {
+        let merchant = aggregated_merchant_with_timestamps(
+            serde_json::json!("15/01/2025"),
+            serde_json::json!("2025-01-15T10:00:00Z"),
+        );
+
+        assert_eq!(merchant.created_at, None);
+        assert_eq!(
+            merchant.updated_at,
+            Some(time::macros::datetime!(2025-01-15 10:00:00))
+        );
+        assert_eq!(AggregatedMerchantInfo::from(merchant).created_at, None);
+    }
+
+    #[test]
+    fn test_tax_registration_id_fills_missing_registration_identifier() {
+        let tax_registration_id = Some(Secret::new("SN-TAX-42".to_string()));
+
+        let request = build_aggregated_merchant_request_from_profile(
+            "Boutique Dakar",
+            None,
+            tax_registration_id.clone(),
+        )
+        .unwrap();
+        assert_eq!(request.business_registration_identifier, tax_registration_id);
+
+        let metadata = WaveConnectorMetadata {
+            business_registration_identifier: Some(Secret::new("REG123".to_string())),
+            ..Default::default()
+        };
+        let request = build_aggregated_merchant_request_from_profile(
+            "Boutique Dakar",
+            Some(&metadata),
+            tax_registration_id,
+        )
+        .unwrap();
+        assert_eq!(
+            request.business_registration_identifier,
+            Some(Secret::new("REG123".to_string()))
+        );
+    }
+
+    #[test]
+    fn test_return_url_validation() {
+        let invalid = ConnectorError::InvalidDataFormat { field_name: "return_url" };
+        for return_url in ["http://merchant.example/return", "not a url", "mailto:ops@merchant.example"] {
+            let error = validate_wave_return_url(return_url, false).unwrap_err();
+            assert_eq!(error.current_context(), &invalid, "{return_url}");
+        }
+        assert!(validate_wave_return_url("https://merchant.example/return", false).is_ok());
+
+        // Local http only in test mode
+        assert!(validate_wave_return_url("http://localhost:8080/return", false).is_err());
+        assert!(validate_wave_return_url("http://localhost:8080/return", true).is_ok());
+        assert!(validate_wave_return_url("http://merchant.example/return", true).is_err());
+    }
+
+    #[test]
+    fn test_http_return_url_is_rejected_in_checkout() {
+        let mut router_data: PaymentsAuthorizeRouterData = build_router_data(authorize_request_data(1000));
+        router_data.request.router_return_url = Some("http://merchant.example/return".to_string());
+        let error = checkout_request_for(&router_data).unwrap_err();
+        assert_eq!(
+            error.current_context(),
+            &ConnectorError::InvalidDataFormat { field_name: "return_url" }
+        );
+    }
+
+    fn checkout_response(amount: &str, currency: &str) -> WaveCheckoutSessionResponse {
+        WaveCheckoutSessionResponse {
+            id: "cos-test123".to_string(),
+            launch_url: Some("https://pay.wave.com/c/cos-test123".to_string()),
+            status: WavePaymentStatus::Created,
+            amount: amount.to_string(),
+            currency: currency.to_string(),
+            reference: Some("pay_wave_test_1".to_string()),
+            aggregated_merchant_id: None,
+        }
+    }
+
+    #[test]
+    fn test_checkout_amount_reconciliation() {
+        let expected = MinorUnit::new(1000);
+        assert_eq!(
+            check_checkout_amount(expected, api_enums::Currency::XOF, &checkout_response("1000", "xof")),
+            None
+        );
+        assert_eq!(
+            check_checkout_amount(expected, api_enums::Currency::XOF, &checkout_response("1005", "XOF")),
+            Some(WaveAmountMismatch {
+                expected_amount: 1000,
+                received_amount: "1005".to_string(),
+                expected_currency: "XOF".to_string(),
+                received_currency: "XOF".to_string(),
+            })
+        );
+        assert!(
+            check_checkout_amount(expected, api_enums::Currency::XOF, &checkout_response("1000", "EUR"))
+                .is_some()
+        );
+    }
+
+    #[test]
+    fn test_checkout_amount_mismatch_does_not_fail_the_payment() {
+        let router_data: PaymentsAuthorizeRouterData = build_router_data(authorize_request_data(1000));
+        let router_data = PaymentsAuthorizeRouterData::try_from(ResponseRouterData {
+            response: checkout_response("1005", "XOF"),
+            data: router_data,
+            http_code: 200,
+        })
+        .unwrap();
+
+        assert_eq!(router_data.status, AttemptStatus::AuthenticationPending);
+        assert!(router_data.response.is_ok());
+    }
+
+    #[test]
+    fn test_business_description_template() {
+        assert_eq!(
+            get_effective_business_description("Boutique Dakar", None),
+            "Payment processing for Boutique Dakar"
+        );
+
+        let metadata = WaveConnectorMetadata {
+            business_description_template: Some("{profile} — powered by Acme".to_string()),
+            ..Default::default()
+        };
+        assert_eq!(
+            get_effective_business_description("Boutique Dakar", Some(&metadata)),
+            "Boutique Dakar — powered by Acme"
+        );
+        let request =
+            build_aggregated_merchant_request_from_profile("Boutique Dakar", Some(&metadata), None)
+                .unwrap();
+        assert_eq!(request.business_description, "Boutique Dakar — powered by Acme");
+    }
+
+    #[test]
+    fn test_business_description_template_overflowing_limit_is_rejected() {
+        let metadata = WaveConnectorMetadata {
+            business_description_template: Some(format!("{{profile}} {}", "a".repeat(490))),
+            ..Default::default()
+        };
+
+        assert!(validate_enhanced_wave_connector_metadata(&metadata, "Shop").is_ok());
+        assert!(matches!(
+            validate_enhanced_wave_connector_metadata(&metadata, "Boutique Dakar"),
+            Err(WaveAggregatedMerchantError::InvalidConfiguration { .. })
+        ));
+    }
+
+    #[test]
+    fn test_description_limit_counts_characters_not_bytes() {
+        // "Boutique Thiès " is 15 characters but 16 bytes, so this renders to exactly 500 characters
+        let metadata = WaveConnectorMetadata {
+            business_description_template: Some(format!("{{profile}} {}", "a".repeat(485))),
+            ..Default::default()
+        };
+
+        assert!(validate_enhanced_wave_connector_metadata(&metadata, "Boutique Thiès").is_ok());
+    }
+
+    #[test]
+    fn test_authorize_response_carries_wave_connector_metadata() {
+        let mut router_data: PaymentsAuthorizeRouterData = build_router_data(authorize_request_data(1000));
+        router_data.connector_meta_data = Some(Secret::new(serde_json::json!({
+            "aggregated_merchant_id": "am-connector123"
+        })));
+        // The session went to a per-payment override, not the account's aggregated merchant
+        let router_data = PaymentsAuthorizeRouterData::try_from(ResponseRouterData {
+            response: WaveCheckoutSessionResponse {
+                aggregated_merchant_id: Some("am-test123".to_string()),
+                ..checkout_response("1000", "XOF")
+            },
+            data: router_data,
+            http_code: 200,
+        })
+        .unwrap();
+
+        let connector_metadata = match router_data.response.unwrap() {
+            PaymentsResponseData::TransactionResponse {
+                connector_metadata, ..
+            } => connector_metadata.unwrap(),
+            _ => panic!("Expected TransactionResponse"),
+        };
+        assert_eq!(
+            connector_metadata,
+            serde_json::json!({
+                "wave_session_id": "cos-test123",
+                "aggregated_merchant_id": "am-test123",
+                "launch_url": "https://pay.wave.com/c/cos-test123"
+            })
+        );
+        let parsed: WavePaymentConnectorMetadata = serde_json::from_value(connector_metadata).unwrap();
+        assert_eq!(parsed.wave_session_id, "cos-test123");
+    }
+
+    #[test]
+    fn test_open_session_without_launch_url_is_rejected() {
+        for (status, launch_url) in [
+            (WavePaymentStatus::Created, None),
+            (WavePaymentStatus::Pending, None),
+            (WavePaymentStatus::Created, Some("not a url".to_string())),
+        ] {
+            let response = WaveCheckoutSessionResponse {
+                status,
+                launch_url,
+                ..checkout_response("1000", "XOF")
+            };
+
+            let error = PaymentsAuthorizeRouterData::try_from(ResponseRouterData {
+                response,
+                data: build_router_data(authorize_request_data(1000)),
+                http_code: 200,
+            })
+            .unwrap_err();
+
+            assert_eq!(error.current_context(), &ConnectorError::ResponseDeserializationFailed);
+        }
+    }
+
+    #[test]
+    fn test_finished_session_without_launch_url_is_accepted() {
+        let response = WaveCheckoutSessionResponse {
+            status: WavePaymentStatus::Completed,
+            launch_url: None,
+            ..checkout_response("1000", "XOF")
+        };
+
+        let router_data = PaymentsAuthorizeRouterData::try_from(ResponseRouterData {
+            response,
+            data: build_router_data(authorize_request_data(1000)),
+            http_code: 200,
+        })
+        .unwrap();
+
+        assert_eq!(router_data.status, AttemptStatus::Charged);
+    }
+
+    fn router_data_with_payment_metadata(payment_metadata: serde_json::Value) -> PaymentsAuthorizeRouterData {
+        let mut router_data: PaymentsAuthorizeRouterData = build_router_data(authorize_request_data(1000));
+        router_data.connector_meta_data = Some(Secret::new(serde_json::json!({
+            "aggregated_merchant_id": "am-connector123"
+        })));
+        router_data.request.metadata = Some(payment_metadata);
+        router_data
+    }
+
+    /// Connector account with aggregated merchants enabled, so the checkout relies on PreProcessing
+    fn aggregated_merchant_router_data(
+        connector_meta_data: serde_json::Value,
+        preprocessing_id: Option<&str>,
+    ) -> PaymentsAuthorizeRouterData {
+        let mut router_data: PaymentsAuthorizeRouterData = build_router_data(authorize_request_data(1000));
+        router_data.connector_auth_type = ConnectorAuthType::BodyKey {
+            api_key: Secret::new("wave_test_key".to_string()),
+            key1: Secret::new(r#"{"enabled":true,"auto_create_on_profile_creation":false,"default_business_type":"ecommerce","cache_ttl_seconds":3600}"#.to_string()),
+        };
+        router_data.connector_meta_data = Some(Secret::new(connector_meta_data));
+        router_data.preprocessing_id = preprocessing_id.map(str::to_string);
+        router_data
+    }
+
+    #[test]
+    fn test_checkout_uses_aggregated_merchant_confirmed_by_pre_processing() {
+        let router_data = aggregated_merchant_router_data(
+            serde_json::json!({ "aggregated_merchant_id": "am-test123" }),
+            Some("am-test123"),
+        );
+
+        assert_eq!(
+            get_wave_checkout_aggregated_merchant_id(&router_data).unwrap(),
+            Some("am-test123".to_string())
+        );
+    }
+
+    #[test]
+    fn test_unconfirmed_aggregated_merchant_is_dropped_by_default() {
+        let router_data = aggregated_merchant_router_data(
+            serde_json::json!({ "aggregated_merchant_id": "am-test123" }),
+            None,
+        );
+
+        assert_eq!(get_wave_checkout_aggregated_merchant_id(&router_data).unwrap(), None);
+    }
+
+    #[test]
+    fn test_unconfirmed_aggregated_merchant_fails_payment_when_configured() {
+        let router_data = aggregated_merchant_router_data(
+            serde_json::json!({
+                "aggregated_merchant_id": "am-test123",
+                "on_resolution_failure": "fail_payment"
+            }),
+            None,
+        );
+
+        let error = get_wave_checkout_aggregated_merchant_id(&router_data).unwrap_err();
+
+        assert!(matches!(
+            error.current_context(),
+            ConnectorError::ProcessingStepFailed(Some(_))
+        ));
+    }
+
+    #[test]
+    fn test_payment_aggregated_merchant_override_wins_over_connector_config() {
+        let request = checkout_request_for(&router_data_with_payment_metadata(serde_json::json!({
+            "wave_aggregated_merchant_id": "am-subbrand456"
+        })))
+        .unwrap();
+
+        assert_eq!(request.aggregated_merchant_id, Some("am-subbrand456".to_string()));
+
+        let request = checkout_request_for(&router_data_with_payment_metadata(serde_json::json!({
+            "order_id": "ord_1"
+        })))
+        .unwrap();
+
+        assert_eq!(request.aggregated_merchant_id, Some("am-connector123".to_string()));
+    }
+
+    #[test]
+    fn test_payment_metadata_without_override_falls_back_to_connector_config() {
+        for payment_metadata in [
+            serde_json::json!("free form note"),
+            serde_json::json!(["order_1"]),
+            serde_json::json!({ "wave_aggregated_merchant_id": null }),
+        ] {
+            let request =
+                checkout_request_for(&router_data_with_payment_metadata(payment_metadata)).unwrap();
+
+            assert_eq!(request.aggregated_merchant_id, Some("am-connector123".to_string()));
+        }
+    }
+
+    #[test]
+    fn test_malformed_payment_aggregated_merchant_override_is_rejected() {
+        for invalid in [serde_json::json!("subbrand456"), serde_json::json!("am-/x"), serde_json::json!(42)] {
+            let error = checkout_request_for(&router_data_with_payment_metadata(serde_json::json!({
+                "wave_aggregated_merchant_id": invalid
+            })))
+            .unwrap_err();
+
+            assert!(matches!(
+                error.current_context(),
+                ConnectorError::InvalidDataFormat {
+                    field_name: "metadata.wave_aggregated_merchant_id"
+                }
+            ));
+        }
+    }
+
+    fn failed_psync_with_reason(failure_reason: Option<&str>) -> PaymentsSyncRouterData {
+        PaymentsSyncRouterData::try_from(ResponseRouterData {
+            response: WavePaymentStatusResponse {
+                status: WavePaymentStatus::Failed,
+                failure_reason: failure_reason.map(str::to_string),
+                ..status_response("1000")
+            },
+            data: build_router_data(PaymentsSyncData::default()),
+            http_code: 200,
+        })
+        .unwrap()
+    }
+
+    #[test]
+    fn test_decline_codes_map_to_failure_with_error_details() {
+        for (code, message) in [
+            ("insufficient_funds", "Insufficient funds in the payer's Wave wallet"),
+            ("payer_cancelled", "Payer cancelled the payment in the Wave app"),
+        ] {
+            let router_data = failed_psync_with_reason(Some(code));
+            assert_eq!(router_data.status, AttemptStatus::Failure);
+            let error = router_data.response.unwrap_err();
+            assert_eq!(error.code, code);
+            assert_eq!(error.message, message);
+            assert_eq!(error.attempt_status, Some(AttemptStatus::Failure));
+            assert_eq!(error.connector_transaction_id, Some("cos-test123".to_string()));
+        }
+    }
+
+    #[test]
+    fn test_unknown_decline_code_keeps_wave_code() {
+        let error = failed_psync_with_reason(Some("wallet_frozen")).response.unwrap_err();
+
+        assert_eq!(WaveDeclineCode::from_reason("wallet_frozen"), WaveDeclineCode::Unknown);
+        assert_eq!(error.code, "wallet_frozen");
+        assert_eq!(error.message, "Payment failed on Wave");
+    }
+
+    #[test]
+    fn test_failed_session_without_reason_stays_a_plain_failure() {
+        let router_data = failed_psync_with_reason(None);
+
+        assert_eq!(router_data.status, AttemptStatus::Failure);
+        assert!(router_data.response.is_ok());
+    }
+
+    fn refund_request_for(refund_amount: i64) -> Result<WaveRefundRequest, error_stack::Report<ConnectorError>> {
+        let router_data: RefundsRouterData<Execute> = build_router_data(refunds_request_data(refund_amount));
+        let item = WaveRouterData::try_from((
+            &api::CurrencyUnit::Minor,
+            router_data.request.currency,
+            router_data.request.minor_refund_amount,
+            &router_data,
+        ))
+        .unwrap();
+        WaveRefundRequest::try_from(&item)
+    }
+
+    #[test]
+    fn test_partial_refund_within_captured_amount_is_accepted() {
+        assert_eq!(refund_request_for(400).unwrap().amount, "400");
+    }
+
+    #[test]
+    fn test_full_refund_of_captured_amount_is_accepted() {
+        assert_eq!(refund_request_for(1000).unwrap().amount, "1000");
+    }
+
+    #[test]
+    fn test_refund_above_captured_amount_is_rejected() {
+        let error = refund_request_for(1001).unwrap_err();
+
+        assert_eq!(
+            error.current_context(),
+            &ConnectorError::RefundAmountExceedsPaymentAmount
+        );
+    }
+
+    #[test]
+    fn test_zero_or_negative_refund_amount_is_rejected() {
+        for refund_amount in [0, -500] {
+            let error = refund_request_for(refund_amount).unwrap_err();
+
+            assert_eq!(
+                error.current_context(),
+                &ConnectorError::InvalidDataFormat { field_name: "refund_amount" }
+            );
+        }
+    }
+
+    #[test]
+    fn test_zero_decimal_currencies_pass_currency_check() {
+        assert!(validate_wave_currency(api_enums::Currency::XOF).is_ok());
+    }
+
+    #[test]
+    fn test_decimal_currencies_are_rejected_regardless_of_amount() {
+        for currency in [api_enums::Currency::EUR, api_enums::Currency::USD] {
+            let error = validate_wave_currency(currency).unwrap_err();
+
+            assert_eq!(
+                error.current_context(),
+                &ConnectorError::CurrencyNotSupported {
+                    message: currency.to_string(),
+                    connector: "wave",
+                }
+            );
+        }
+    }
+
+    #[test]
+    fn test_xof_checkout_and_refund_amounts_are_sent_whole() {
+        assert_eq!(checkout_request_for(&router_data_for_amount(2500)).unwrap().amount, "2500");
+        assert_eq!(refund_request_for(250).unwrap().amount, "250");
+    }
+
+    fn empty_update_request() -> WaveAggregatedMerchantUpdateRequest {
+        WaveAggregatedMerchantUpdateRequest {
+            name: None,
+            business_type: None,
+            business_registration_identifier: None,
+            business_sector: None,
+            website_url: None,
+            business_description: None,
+            manager_name: None,
+            clear_fields: Vec::new(),
+        }
+    }
+
+    #[test]
+    fn test_update_body_sends_cleared_fields_as_null_and_omits_unchanged_ones() {
+        let request = WaveAggregatedMerchantUpdateRequest {
+            name: Some("Boutique Thies".to_string()),
+            clear_fields: vec![WaveAggregatedMerchantClearableField::WebsiteUrl],
+            ..empty_update_request()
+        };
+
+        assert_eq!(
+            request.to_wave_body().unwrap(),
+            serde_json::json!({ "name": "Boutique Thies", "website_url": null })
+        );
+    }
+
+    #[test]
+    fn test_update_body_rejects_setting_and_clearing_the_same_field() {
+        let request = WaveAggregatedMerchantUpdateRequest {
+            website_url: Some("https://boutique.example.sn".to_string()),
+            clear_fields: vec![WaveAggregatedMerchantClearableField::WebsiteUrl],
+            ..empty_update_request()
+        };
+
+        assert!(matches!(
+            request.to_wave_body().unwrap_err().current_context(),
+            ConnectorError::InvalidDataFormat { field_name: "clear_fields" }
+        ));
+    }
+
+    #[test]
+    fn test_checkout_response_tolerates_unknown_and_missing_optional_fields() {
+        let response: WaveCheckoutSessionResponse = serde_json::from_value(serde_json::json!({
+            "id": "cos-test123",
+            "status": "pending",
+            "amount": "1000",
+            "currency": "XOF",
+            "checkout_status": "open",
+            "when_expires": "2025-01-15T11:00:00Z"
+        }))
+        .unwrap();
+
+        assert_eq!(response.id, "cos-test123");
+        assert_eq!(response.launch_url, None);
+        assert_eq!(response.reference, None);
+    }
+
+    #[test]
+    fn test_aggregated_merchant_and_error_bodies_tolerate_missing_fields() {
+        let merchant: WaveAggregatedMerchant = serde_json::from_value(serde_json::json!({
+            "id": "am-test123",
+            "name": "Boutique Dakar",
+            "business_type": "ecommerce",
+            "status": "active",
+            "is_locked": false
+        }))
+        .unwrap();
+        assert_eq!(merchant.business_description, "");
+        assert_eq!(merchant.website_url, None);
+
+        let error: WaveErrorResponse =
+            serde_json::from_value(serde_json::json!({ "code": "AGGREGATED_MERCHANT_NOT_FOUND" }))
+                .unwrap();
+        assert_eq!(error.error_code(), WaveErrorCode::AggregatedMerchantNotFound);
+        assert_eq!(error.message, "");
+
+        let list: WaveAggregatedMerchantListResponse =
+            serde_json::from_value(serde_json::json!({ "total_count": 0 })).unwrap();
+        assert!(list.aggregated_merchants.is_empty());
+    }
+
+    #[test]
+    fn test_preferred_provider_is_sent_when_configured() {
+        let request = checkout_request(Some(serde_json::json!({
+            "preferred_provider": "Orange_Money"
+        })));
+        assert_eq!(request.preferred_provider, Some(WavePaymentProvider::OrangeMoney));
+        assert_eq!(
+            serde_json::to_value(&request).unwrap()["preferred_provider"],
+            serde_json::json!("orange_money")
+        );
+
+        let request = checkout_request(None);
+        assert!(serde_json::to_value(&request)
+            .unwrap()
+            .get("preferred_provider")
+            .is_none());
+    }
+
+    #[test]
+    fn test_unknown_preferred_provider_is_rejected() {
+        let mut router_data: PaymentsAuthorizeRouterData = build_router_data(authorize_request_data(1000));
+        router_data.connector_meta_data = Some(Secret::new(serde_json::json!({
+            "preferred_provider": "paypal"
+        })));
+        let error = checkout_request_for(&router_data).unwrap_err();
+
+        assert_eq!(
+            error.current_context(),
+            &ConnectorError::InvalidDataFormat {
+                field_name: "preferred_provider"
+            }
+        );
+    }
+
+    #[test]
+    fn test_marketplace_commission_is_sent_for_marketplace_merchants() {
+        let request = checkout_request(Some(serde_json::json!({
+            "business_type": "marketplace",
+            "marketplace_commission": { "bps": 250 }
+        })));
+        assert_eq!(
+            request.platform_commission,
+            Some(WavePlatformCommission { amount: "25".to_string() })
+        );
+
+        let request = checkout_request(Some(serde_json::json!({
+            "business_type": "marketplace",
+            "marketplace_commission": { "amount": 150 }
+        })));
+        assert_eq!(
+            serde_json::to_value(&request).unwrap()["platform_commission"],
+            serde_json::json!({ "amount": "150" })
+        );
+    }
+
+    #[test]
+    fn test_commission_is_omitted_outside_marketplaces() {
+        let request = checkout_request(Some(serde_json::json!({
+            "business_type": "ecommerce",
+            "marketplace_commission": { "amount": 150 }
+        })));
+
+        assert_eq!(request.platform_commission, None);
+        assert!(serde_json::to_value(&request).unwrap().get("platform_commission").is_none());
+    }
+
+    #[test]
+    fn test_commission_above_the_payment_total_is_rejected() {
+        let mut router_data: PaymentsAuthorizeRouterData = build_router_data(authorize_request_data(1000));
+        router_data.connector_meta_data = Some(Secret::new(serde_json::json!({
+            "business_type": "marketplace",
+            "marketplace_commission": { "amount": 1001 }
+        })));
+        let error = checkout_request_for(&router_data).unwrap_err();
+
+        assert!(matches!(
+            error.current_context(),
+            ConnectorError::InvalidDataFormat { field_name: "marketplace_commission" }
+        ));
+        assert!(get_wave_platform_commission(
+            MinorUnit::new(1000),
+            Some(&WaveMarketplaceCommission::Bps(10_001))
+        )
+        .is_err());
+    }
+
+    #[test]
+    fn test_blank_reference_is_omitted_from_checkout_request() {
+        for blank in ["", "   "] {
+            let mut router_data: PaymentsAuthorizeRouterData = build_router_data(authorize_request_data(1000));
+            router_data.connector_request_reference_id = blank.to_string();
+            let request = checkout_request_for(&router_data).unwrap();
+
+            assert_eq!(request.reference, None);
+            assert!(serde_json::to_value(&request).unwrap().get("reference").is_none());
+        }
+    }
+
+    #[test]
+    fn test_blank_refund_reason_is_omitted_from_refund_request() {
+        let mut router_data: RefundsRouterData<Execute> = build_router_data(refunds_request_data(500));
+        router_data.request.reason = Some("  ".to_string());
+        let item = WaveRouterData::try_from((
+            &api::CurrencyUnit::Minor,
+            router_data.request.currency,
+            router_data.request.minor_refund_amount,
+            &router_data,
+        ))
+        .unwrap();
+        let request = WaveRefundRequest::try_from(&item).unwrap();
+
+        assert_eq!(request.reason, None);
+        assert!(serde_json::to_value(&request).unwrap().get("reason").is_none());
+    }
 }
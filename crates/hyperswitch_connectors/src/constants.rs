@@ -23,6 +23,7 @@ pub(crate) mod headers {
     pub(crate) const X_TRANS_KEY: &str = "X-Trans-Key";
     pub(crate) const X_RANDOM_VALUE: &str = "X-RandomValue";
     pub(crate) const X_REQUEST_DATE: &str = "X-RequestDate";
+    pub(crate) const X_REQUEST_ID: &str = "X-Request-Id";
     pub(crate) const X_VERSION: &str = "X-Version";
     pub(crate) const X_API_KEY: &str = "X-Api-Key";
     pub(crate) const CORRELATION_ID: &str = "Correlation-Id";
@@ -1782,7 +1782,6 @@ default_imp_for_pre_processing_steps!(
     connectors::Wellsfargo,
     connectors::Wellsfargopayout,
     connectors::Volt,
-    connectors::Wave,
     connectors::Zen,
     connectors::Zsl,
     connectors::CtpMastercard
@@ -131,7 +131,7 @@ pub struct Connectors {
     pub unified_authentication_service: ConnectorParams,
     pub vgs: ConnectorParams,
     pub volt: ConnectorParams,
-    pub wave: ConnectorParams,
+    pub wave: WaveConnectorConfig,
     pub wellsfargo: ConnectorParams,
     pub wellsfargopayout: ConnectorParams,
     pub wise: ConnectorParams,
@@ -244,6 +244,33 @@ pub struct ConnectorParamsWithSecondaryBaseUrl {
     /// secondary base url
     pub secondary_base_url: String,
 }
+/// struct WaveConnectorConfig
+#[derive(Debug, Deserialize, Clone, Default, router_derive::ConfigValidate)]
+#[serde(default)]
+pub struct WaveConnectorConfig {
+    /// base url
+    pub base_url: String,
+    /// base url for the aggregated merchant and webhook services, defaults to the base url
+    pub merchant_service_base_url: Option<String>,
+    /// timeout in seconds for the raw Wave service requests
+    pub request_timeout_secs: Option<u64>,
+    /// number of attempts when validating an aggregated merchant
+    pub max_retries: Option<u32>,
+    /// largest response body in bytes accepted from the raw Wave service requests
+    pub max_response_body_bytes: Option<usize>,
+    /// temporary aggregated merchants the process may be creating at the same time
+    pub max_concurrent_temporary_merchants: Option<usize>,
+}
+
+impl WaveConnectorConfig {
+    /// base url for the aggregated merchant and webhook services
+    pub fn get_merchant_service_base_url(&self) -> &str {
+        self.merchant_service_base_url
+            .as_deref()
+            .unwrap_or(&self.base_url)
+    }
+}
+
 /// struct ConnectorParamsWithThreeUrls
 #[derive(Debug, Deserialize, Clone, Default, router_derive::ConfigValidate)]
 #[serde(default)]
@@ -372,6 +372,15 @@ pub trait ConnectorCommon {
             connector_metadata: None,
         })
     }
+
+    /// Apply the merchant account's event settings to the connector event before it is logged,
+    /// whether the call succeeded or failed
+    fn apply_event_verbosity(
+        &self,
+        _event: &mut ConnectorEvent,
+        _connector_meta_data: Option<&common_utils::pii::SecretSerdeValue>,
+    ) {
+    }
 }
 
 /// The trait that provides specifications about the connector
@@ -692,6 +701,16 @@ pub trait ConnectorValidation: ConnectorCommon + ConnectorSpecifications {
             .map(|_| ())
     }
 
+    /// Validate the authorize amount against the limits the connector enforces,
+    /// before the authorize request is built
+    fn validate_authorize_amount(
+        &self,
+        _amount: common_utils::types::MinorUnit,
+        _connector_meta_data: Option<common_utils::pii::SecretSerdeValue>,
+    ) -> CustomResult<(), errors::ConnectorError> {
+        Ok(())
+    }
+
     /// fn is_webhook_source_verification_mandatory
     fn is_webhook_source_verification_mandatory(&self) -> bool {
         false
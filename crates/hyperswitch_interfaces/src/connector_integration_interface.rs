@@ -487,6 +487,17 @@ impl ConnectorValidation for ConnectorEnum {
         }
     }
 
+    fn validate_authorize_amount(
+        &self,
+        amount: common_utils::types::MinorUnit,
+        connector_meta_data: Option<common_utils::pii::SecretSerdeValue>,
+    ) -> CustomResult<(), errors::ConnectorError> {
+        match self {
+            Self::Old(connector) => connector.validate_authorize_amount(amount, connector_meta_data),
+            Self::New(connector) => connector.validate_authorize_amount(amount, connector_meta_data),
+        }
+    }
+
     fn is_webhook_source_verification_mandatory(&self) -> bool {
         match self {
             Self::Old(connector) => connector.is_webhook_source_verification_mandatory(),
@@ -615,6 +626,17 @@ impl ConnectorCommon for ConnectorEnum {
             Self::New(connector) => connector.build_error_response(res, event_builder),
         }
     }
+
+    fn apply_event_verbosity(
+        &self,
+        event: &mut ConnectorEvent,
+        connector_meta_data: Option<&common_utils::pii::SecretSerdeValue>,
+    ) {
+        match self {
+            Self::Old(connector) => connector.apply_event_verbosity(event, connector_meta_data),
+            Self::New(connector) => connector.apply_event_verbosity(event, connector_meta_data),
+        }
+    }
 }
 
 /// Trait representing the connector integration interface
@@ -691,6 +713,17 @@ pub trait ConnectorIntegrationInterface<F, ResourceCommonData, Req, Resp>: Send
         res: types::Response,
         event_builder: Option<&mut ConnectorEvent>,
     ) -> CustomResult<ErrorResponse, errors::ConnectorError>;
+    /// Apply the merchant account's event settings to the connector event
+    ///
+    /// # Arguments
+    ///
+    /// * `event` - The connector event about to be logged
+    /// * `connector_meta_data` - The merchant connector account metadata
+    fn apply_event_verbosity(
+        &self,
+        event: &mut ConnectorEvent,
+        connector_meta_data: Option<&common_utils::pii::SecretSerdeValue>,
+    );
 }
 
 impl<T: 'static, ResourceCommonData: 'static, Req: 'static, Resp: 'static>
@@ -781,6 +814,21 @@ where
         }
     }
 
+    fn apply_event_verbosity(
+        &self,
+        event: &mut ConnectorEvent,
+        connector_meta_data: Option<&common_utils::pii::SecretSerdeValue>,
+    ) {
+        match self {
+            ConnectorIntegrationEnum::Old(old_integration) => {
+                old_integration.apply_event_verbosity(event, connector_meta_data)
+            }
+            ConnectorIntegrationEnum::New(new_integration) => {
+                new_integration.apply_event_verbosity(event, connector_meta_data)
+            }
+        }
+    }
+
     fn clone_box(
         &self,
     ) -> Box<dyn ConnectorIntegrationInterface<T, ResourceCommonData, Req, Resp> + Send + Sync>
@@ -38,6 +38,8 @@ pub enum ConnectorError {
     MissingRequiredFields { field_names: Vec<&'static str> },
     #[error("Failed to obtain authentication type")]
     FailedToObtainAuthType,
+    #[error("The connector rejected the configured credentials")]
+    AuthenticationFailed,
     #[error("Failed to obtain certificate")]
     FailedToObtainCertificate,
     #[error("Connector meta data not found")]
@@ -120,6 +122,8 @@ pub enum ConnectorError {
     InvalidConnectorConfig { config: &'static str },
     #[error("Failed to convert amount to required type")]
     AmountConversionFailed,
+    #[error("The refund amount exceeds the amount captured")]
+    RefundAmountExceedsPaymentAmount,
     #[error("Generic Error")]
     GenericError {
         error_message: String,
@@ -72,6 +72,16 @@ impl ConnectorEvent {
         }
     }
 
+    /// fn set_request_body
+    pub fn set_request_body<T: Serialize>(&mut self, request: &T) {
+        match masking::masked_serialize(request) {
+            Ok(masked) => {
+                self.request = masked.to_string();
+            }
+            Err(er) => self.set_error(json!({"error": er.to_string()})),
+        }
+    }
+
     /// fn set_response_body
     pub fn set_response_body<T: Serialize>(&mut self, response: &T) {
         match masking::masked_serialize(response) {
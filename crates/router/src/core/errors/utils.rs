@@ -139,6 +139,9 @@ impl<T> ConnectorErrorExt<T> for error_stack::Result<T, errors::ConnectorError>
                 }
                 .into()
             }
+            errors::ConnectorError::RefundAmountExceedsPaymentAmount => {
+                err.change_context(errors::ApiErrorResponse::RefundAmountExceedsPaymentAmount)
+            }
             errors::ConnectorError::FailedToObtainIntegrationUrl
             | errors::ConnectorError::RequestEncodingFailed
             | errors::ConnectorError::RequestEncodingFailedWithReason(_)
@@ -154,6 +157,7 @@ impl<T> ConnectorErrorExt<T> for error_stack::Result<T, errors::ConnectorError>
             | errors::ConnectorError::MissingRequiredField { .. }
             | errors::ConnectorError::MissingRequiredFields { .. }
             | errors::ConnectorError::FailedToObtainAuthType
+            | errors::ConnectorError::AuthenticationFailed
             | errors::ConnectorError::FailedToObtainCertificate
             | errors::ConnectorError::NoConnectorMetaData
             | errors::ConnectorError::NoConnectorWalletDetails
@@ -258,7 +262,7 @@ impl<T> ConnectorErrorExt<T> for error_stack::Result<T, errors::ConnectorError>
                 }
                 errors::ConnectorError::InvalidWalletToken {wallet_name} => errors::ApiErrorResponse::InvalidWalletToken {wallet_name: wallet_name.to_string()},
                 errors::ConnectorError::CurrencyNotSupported { message, connector} => errors::ApiErrorResponse::CurrencyNotSupported { message: format!("Credentials for the currency {message} are not configured with the connector {connector}/hyperswitch") },
-                errors::ConnectorError::FailedToObtainAuthType =>  errors::ApiErrorResponse::InvalidConnectorConfiguration {config: "connector_account_details".to_string()},
+                errors::ConnectorError::FailedToObtainAuthType | errors::ConnectorError::AuthenticationFailed =>  errors::ApiErrorResponse::InvalidConnectorConfiguration {config: "connector_account_details".to_string()},
                 errors::ConnectorError::InvalidConnectorConfig { config }  => errors::ApiErrorResponse::InvalidConnectorConfiguration { config: config.to_string() },
                 errors::ConnectorError::FailedToObtainIntegrationUrl |
                 errors::ConnectorError::RequestEncodingFailed |
@@ -300,6 +304,7 @@ impl<T> ConnectorErrorExt<T> for error_stack::Result<T, errors::ConnectorError>
                 errors::ConnectorError::RequestTimeoutReceived |
                 errors::ConnectorError::ProcessingStepFailed(None)|
                 errors::ConnectorError::GenericError {..} |
+                errors::ConnectorError::RefundAmountExceedsPaymentAmount |
                 errors::ConnectorError::AmountConversionFailed => errors::ApiErrorResponse::InternalServerError
             };
             err.change_context(error)
@@ -362,6 +367,7 @@ impl<T> ConnectorErrorExt<T> for error_stack::Result<T, errors::ConnectorError>
                 | errors::ConnectorError::ResponseHandlingFailed
                 | errors::ConnectorError::MissingRequiredFields { .. }
                 | errors::ConnectorError::FailedToObtainAuthType
+                | errors::ConnectorError::AuthenticationFailed
                 | errors::ConnectorError::FailedToObtainCertificate
                 | errors::ConnectorError::NoConnectorMetaData
                 | errors::ConnectorError::NoConnectorWalletDetails
@@ -400,6 +406,7 @@ impl<T> ConnectorErrorExt<T> for error_stack::Result<T, errors::ConnectorError>
                 | errors::ConnectorError::CurrencyNotSupported { .. }
                 | errors::ConnectorError::ProcessingStepFailed(None)
                 | errors::ConnectorError::AmountConversionFailed
+                | errors::ConnectorError::RefundAmountExceedsPaymentAmount
                 | errors::ConnectorError::GenericError { .. } => {
                     logger::error!(%error,"Setup Mandate flow failed");
                     errors::ApiErrorResponse::PaymentAuthorizationFailed { data: None }
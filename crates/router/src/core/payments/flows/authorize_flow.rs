@@ -356,6 +356,14 @@ impl Feature<api::Authorize, types::PaymentsAuthorizeData> for types::PaymentsAu
                     )
                     .to_payment_failed_response()?;
 
+                connector
+                    .connector
+                    .validate_authorize_amount(
+                        self.request.minor_amount,
+                        self.connector_meta_data.clone(),
+                    )
+                    .to_payment_failed_response()?;
+
                 // Check if the connector supports mandate payment
                 // if the payment_method_type does not support mandate for the given connector, downgrade the setup future usage to on session
                 if self.request.setup_future_usage
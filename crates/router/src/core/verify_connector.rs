@@ -29,6 +29,11 @@ pub async fn verify_connector_credentials(
     )
     .change_context(errors::ApiErrorResponse::IncorrectConnectorNameGiven)?;
 
+    // Wave has no card flow to test with, so its credentials are checked with a lightweight call
+    if let Connector::Wave = req.connector_name {
+        return verify_wave_credentials(&state, req).await;
+    }
+
     let card_details = utils::get_test_card_details(req.connector_name)?.ok_or(
         errors::ApiErrorResponse::FlowNotSupported {
             flow: "Verify credentials".to_string(),
@@ -65,3 +70,32 @@ pub async fn verify_connector_credentials(
         .into()),
     }
 }
+
+async fn verify_wave_credentials(
+    state: &SessionState,
+    req: VerifyConnectorRequest,
+) -> errors::RouterResponse<()> {
+    let connector_auth: crate::types::ConnectorAuthType =
+        req.connector_account_details.foreign_into();
+    let auth = connector::wave::transformers::WaveAuthType::try_from(&connector_auth)
+        .change_context(errors::ApiErrorResponse::InvalidRequestData {
+            message: "The auth type is invalid for the connector".to_string(),
+        })?;
+
+    connector::Wave::validate_credentials(
+        &auth,
+        state.conf.connectors.wave.get_merchant_service_base_url(),
+    )
+    .await
+    .map_err(|error| match error.current_context() {
+        // Only a rejected key means the credentials are invalid, anything else is a failed check
+        errors::ConnectorError::AuthenticationFailed => {
+            error.change_context(errors::ApiErrorResponse::InvalidRequestData {
+                message: "Wave rejected the configured credentials".to_string(),
+            })
+        }
+        _ => error.change_context(errors::ApiErrorResponse::InternalServerError),
+    })?;
+
+    Ok(services::ApplicationResponse::StatusOk)
+}
@@ -29,6 +29,18 @@ pub trait SubscriptionInterface {
         subscription_id: String,
         data: storage::SubscriptionUpdate,
     ) -> CustomResult<storage::Subscription, errors::StorageError>;
+
+    #[allow(clippy::too_many_arguments)]
+    async fn list_subscriptions_by_merchant_id(
+        &self,
+        merchant_id: &common_utils::id_type::MerchantId,
+        status: Option<String>,
+        customer_id: Option<common_utils::id_type::CustomerId>,
+        created_after: Option<time::PrimitiveDateTime>,
+        created_before: Option<time::PrimitiveDateTime>,
+        limit: Option<i64>,
+        offset: Option<i64>,
+    ) -> CustomResult<Vec<storage::Subscription>, errors::StorageError>;
 }
 
 #[async_trait::async_trait]
@@ -73,6 +85,32 @@ impl SubscriptionInterface for Store {
             .await
             .map_err(|error| report!(errors::StorageError::from(error)))
     }
+
+    #[instrument(skip_all)]
+    async fn list_subscriptions_by_merchant_id(
+        &self,
+        merchant_id: &common_utils::id_type::MerchantId,
+        status: Option<String>,
+        customer_id: Option<common_utils::id_type::CustomerId>,
+        created_after: Option<time::PrimitiveDateTime>,
+        created_before: Option<time::PrimitiveDateTime>,
+        limit: Option<i64>,
+        offset: Option<i64>,
+    ) -> CustomResult<Vec<storage::Subscription>, errors::StorageError> {
+        let conn = connection::pg_connection_read(self).await?;
+        storage::Subscription::list_by_merchant_id_constraints(
+            &conn,
+            merchant_id,
+            status,
+            customer_id,
+            created_after,
+            created_before,
+            limit,
+            offset,
+        )
+        .await
+        .map_err(|error| report!(errors::StorageError::from(error)))
+    }
 }
 
 #[async_trait::async_trait]
@@ -101,6 +139,47 @@ impl SubscriptionInterface for MockDb {
     ) -> CustomResult<storage::Subscription, errors::StorageError> {
         Err(errors::StorageError::MockDbError)?
     }
+
+    async fn list_subscriptions_by_merchant_id(
+        &self,
+        merchant_id: &common_utils::id_type::MerchantId,
+        status: Option<String>,
+        customer_id: Option<common_utils::id_type::CustomerId>,
+        created_after: Option<time::PrimitiveDateTime>,
+        created_before: Option<time::PrimitiveDateTime>,
+        limit: Option<i64>,
+        offset: Option<i64>,
+    ) -> CustomResult<Vec<storage::Subscription>, errors::StorageError> {
+        let locked_subscriptions = self.subscriptions.lock().await;
+        let limit_usize = limit
+            .unwrap_or(i64::MAX)
+            .try_into()
+            .unwrap_or(usize::MAX);
+        let offset_usize = offset.unwrap_or(0).try_into().unwrap_or(usize::MIN);
+        let mut filtered_subscriptions: Vec<storage::Subscription> = locked_subscriptions
+            .iter()
+            .filter(|subscription| {
+                subscription.merchant_id == *merchant_id
+                    && status
+                        .as_ref()
+                        .map_or(true, |status| &subscription.status == status)
+                    && customer_id
+                        .as_ref()
+                        .map_or(true, |id| &subscription.customer_id == id)
+                    && created_after.map_or(true, |after| subscription.created_at >= after)
+                    && created_before.map_or(true, |before| subscription.created_at <= before)
+            })
+            .cloned()
+            .collect();
+        // Same ordering as the database query: newest first
+        filtered_subscriptions.sort_by(|a, b| b.created_at.cmp(&a.created_at));
+
+        Ok(filtered_subscriptions
+            .into_iter()
+            .skip(offset_usize)
+            .take(limit_usize)
+            .collect())
+    }
 }
 
 #[async_trait::async_trait]
@@ -137,4 +216,230 @@ impl SubscriptionInterface for KafkaStore {
             .update_subscription_entry(merchant_id, subscription_id, data)
             .await
     }
+
+    #[instrument(skip_all)]
+    async fn list_subscriptions_by_merchant_id(
+        &self,
+        merchant_id: &common_utils::id_type::MerchantId,
+        status: Option<String>,
+        customer_id: Option<common_utils::id_type::CustomerId>,
+        created_after: Option<time::PrimitiveDateTime>,
+        created_before: Option<time::PrimitiveDateTime>,
+        limit: Option<i64>,
+        offset: Option<i64>,
+    ) -> CustomResult<Vec<storage::Subscription>, errors::StorageError> {
+        self.diesel_store
+            .list_subscriptions_by_merchant_id(
+                merchant_id,
+                status,
+                customer_id,
+                created_after,
+                created_before,
+                limit,
+                offset,
+            )
+            .await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    #![allow(clippy::expect_used, clippy::unwrap_used)]
+    mod mockdb_subscription_interface {
+        use std::borrow::Cow;
+
+        use redis_interface::RedisSettings;
+        use time::macros::datetime;
+
+        use crate::{
+            db::{subscription::SubscriptionInterface, MockDb},
+            types::storage,
+        };
+
+        fn create_subscription(
+            subscription_id: &str,
+            merchant_id: &common_utils::id_type::MerchantId,
+            customer_id: &str,
+            status: &str,
+            created_at: time::PrimitiveDateTime,
+        ) -> storage::Subscription {
+            storage::Subscription {
+                id: 0,
+                subscription_id: subscription_id.into(),
+                status: status.into(),
+                billing_processor: None,
+                payment_method_id: None,
+                mca_id: None,
+                client_secret: None,
+                connector_subscription_id: None,
+                merchant_id: merchant_id.clone(),
+                customer_id: common_utils::id_type::CustomerId::try_from(Cow::from(
+                    customer_id.to_string(),
+                ))
+                .unwrap(),
+                metadata: None,
+                created_at,
+                modified_at: created_at,
+            }
+        }
+
+        async fn seeded_mockdb(merchant_id: &common_utils::id_type::MerchantId) -> MockDb {
+            let mockdb = MockDb::new(&RedisSettings::default())
+                .await
+                .expect("Failed to create a mock DB");
+            let other_merchant_id =
+                common_utils::id_type::MerchantId::try_from(Cow::from("merchant_2")).unwrap();
+
+            mockdb.subscriptions.lock().await.extend([
+                create_subscription(
+                    "sub_1",
+                    merchant_id,
+                    "cus_1",
+                    "active",
+                    datetime!(2025-01-01 0:00),
+                ),
+                create_subscription(
+                    "sub_2",
+                    merchant_id,
+                    "cus_2",
+                    "cancelled",
+                    datetime!(2025-01-02 0:00),
+                ),
+                create_subscription(
+                    "sub_3",
+                    merchant_id,
+                    "cus_1",
+                    "active",
+                    datetime!(2025-01-03 0:00),
+                ),
+                create_subscription(
+                    "sub_4",
+                    &other_merchant_id,
+                    "cus_1",
+                    "active",
+                    datetime!(2025-01-04 0:00),
+                ),
+            ]);
+            mockdb
+        }
+
+        fn subscription_ids(subscriptions: &[storage::Subscription]) -> Vec<&str> {
+            subscriptions
+                .iter()
+                .map(|subscription| subscription.subscription_id.as_str())
+                .collect()
+        }
+
+        #[tokio::test]
+        async fn test_list_subscriptions_returns_merchant_subscriptions_newest_first() {
+            let merchant_id =
+                common_utils::id_type::MerchantId::try_from(Cow::from("merchant_1")).unwrap();
+            let mockdb = seeded_mockdb(&merchant_id).await;
+
+            let subscriptions = mockdb
+                .list_subscriptions_by_merchant_id(&merchant_id, None, None, None, None, None, None)
+                .await
+                .unwrap();
+
+            assert_eq!(subscription_ids(&subscriptions), vec!["sub_3", "sub_2", "sub_1"]);
+        }
+
+        #[tokio::test]
+        async fn test_list_subscriptions_filters_by_status_customer_and_created_range() {
+            let merchant_id =
+                common_utils::id_type::MerchantId::try_from(Cow::from("merchant_1")).unwrap();
+            let mockdb = seeded_mockdb(&merchant_id).await;
+            let customer_id =
+                common_utils::id_type::CustomerId::try_from(Cow::from("cus_1")).unwrap();
+
+            let by_status = mockdb
+                .list_subscriptions_by_merchant_id(
+                    &merchant_id,
+                    Some("cancelled".into()),
+                    None,
+                    None,
+                    None,
+                    None,
+                    None,
+                )
+                .await
+                .unwrap();
+            let by_customer = mockdb
+                .list_subscriptions_by_merchant_id(
+                    &merchant_id,
+                    None,
+                    Some(customer_id),
+                    None,
+                    None,
+                    None,
+                    None,
+                )
+                .await
+                .unwrap();
+            let by_created_range = mockdb
+                .list_subscriptions_by_merchant_id(
+                    &merchant_id,
+                    None,
+                    None,
+                    Some(datetime!(2025-01-02 0:00)),
+                    Some(datetime!(2025-01-02 23:59)),
+                    None,
+                    None,
+                )
+                .await
+                .unwrap();
+
+            assert_eq!(subscription_ids(&by_status), vec!["sub_2"]);
+            assert_eq!(subscription_ids(&by_customer), vec!["sub_3", "sub_1"]);
+            assert_eq!(subscription_ids(&by_created_range), vec!["sub_2"]);
+        }
+
+        #[tokio::test]
+        async fn test_list_subscriptions_paginates_with_limit_and_offset() {
+            let merchant_id =
+                common_utils::id_type::MerchantId::try_from(Cow::from("merchant_1")).unwrap();
+            let mockdb = seeded_mockdb(&merchant_id).await;
+
+            let first_page = mockdb
+                .list_subscriptions_by_merchant_id(
+                    &merchant_id,
+                    None,
+                    None,
+                    None,
+                    None,
+                    Some(2),
+                    Some(0),
+                )
+                .await
+                .unwrap();
+            let second_page = mockdb
+                .list_subscriptions_by_merchant_id(
+                    &merchant_id,
+                    None,
+                    None,
+                    None,
+                    None,
+                    Some(2),
+                    Some(2),
+                )
+                .await
+                .unwrap();
+            let past_the_end = mockdb
+                .list_subscriptions_by_merchant_id(
+                    &merchant_id,
+                    None,
+                    None,
+                    None,
+                    None,
+                    Some(2),
+                    Some(4),
+                )
+                .await
+                .unwrap();
+
+            assert_eq!(subscription_ids(&first_page), vec!["sub_3", "sub_2"]);
+            assert_eq!(subscription_ids(&second_page), vec!["sub_1"]);
+            assert!(past_the_end.is_empty());
+        }
+    }
 }
@@ -271,6 +271,12 @@ pub async fn start_server(conf: settings::Settings<SecuredSecret>) -> Applicatio
     let api_client = Box::new(services::ProxyClient::new(&conf.proxy).map_err(|error| {
         errors::ApplicationError::ApiClientError(error.current_context().clone())
     })?);
+    hyperswitch_connectors::connectors::wave::init_wave_service_client(&conf.connectors.wave)
+        .map_err(|error| {
+            errors::ApplicationError::InvalidConfigurationValueError(format!(
+                "connectors.wave: {error:?}"
+            ))
+        })?;
     let state = Box::pin(AppState::new(conf, tx, api_client)).await;
     let request_body_limit = server.request_body_limit;
 
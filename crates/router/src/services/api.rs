@@ -351,6 +351,8 @@ where
                         req.dispute_id.clone(),
                         status_code,
                     );
+                    connector_integration
+                        .apply_event_verbosity(&mut connector_event, req.connector_meta_data.as_ref());
 
                     match response {
                         Ok(body) => {
@@ -41,6 +41,7 @@ pub struct MockDb {
     pub cards_info: Arc<Mutex<Vec<store::CardInfo>>>,
     pub events: Arc<Mutex<Vec<store::Event>>>,
     pub disputes: Arc<Mutex<Vec<store::Dispute>>>,
+    pub subscriptions: Arc<Mutex<Vec<store::subscription::Subscription>>>,
     pub lockers: Arc<Mutex<Vec<store::LockerMockUp>>>,
     pub mandates: Arc<Mutex<Vec<store::Mandate>>>,
     pub captures: Arc<Mutex<Vec<store::capture::Capture>>>,
@@ -90,6 +91,7 @@ impl MockDb {
             cards_info: Default::default(),
             events: Default::default(),
             disputes: Default::default(),
+            subscriptions: Default::default(),
             lockers: Default::default(),
             mandates: Default::default(),
             captures: Default::default(),
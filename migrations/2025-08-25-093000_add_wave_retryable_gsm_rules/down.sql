@@ -0,0 +1,6 @@
+-- This file should undo anything in `up.sql`
+DELETE FROM gateway_status_map
+WHERE connector = 'wave'
+    AND flow = 'Authorize'
+    AND sub_flow = 'sub_flow'
+    AND code IN ('WAVE_SERVICE_UNAVAILABLE', 'WAVE_INTERNAL_ERROR');
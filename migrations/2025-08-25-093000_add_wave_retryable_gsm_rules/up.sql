@@ -0,0 +1,7 @@
+-- Your SQL goes here
+-- Wave 5xx answers are mapped to these codes by the connector and are safe to retry
+INSERT INTO gateway_status_map (connector, flow, sub_flow, code, message, status, decision, step_up_possible)
+VALUES
+    ('wave', 'Authorize', 'sub_flow', 'WAVE_SERVICE_UNAVAILABLE', 'Wave is temporarily unavailable', 'failure', 'retry', FALSE),
+    ('wave', 'Authorize', 'sub_flow', 'WAVE_INTERNAL_ERROR', 'Wave failed to process the request', 'failure', 'retry', FALSE)
+ON CONFLICT (connector, flow, sub_flow, code, message) DO NOTHING;